@@ -4,13 +4,13 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use eipw_lint::fetch::Fetch;
+use eipw_lint::fetch::{Entry, Fetch};
 use eipw_lint::lints::{DefaultLint, Lint};
 use eipw_lint::modifiers::{DefaultModifier, Modifier};
 use eipw_lint::reporters::{AdditionalHelp, Json};
 use eipw_lint::{default_lints, Linter, Options};
 
-use js_sys::{JsString, Object};
+use js_sys::{Array, JsString, Object, Reflect};
 
 use serde::{Deserialize, Serialize};
 
@@ -38,6 +38,53 @@ impl std::error::Error for Error {}
 extern "C" {
     #[wasm_bindgen(catch, js_name = readFile)]
     async fn read_file(path: &JsString, encoding: &JsString) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_name = readdir)]
+    async fn read_dir(path: &JsString, options: &Object) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch)]
+    async fn stat(path: &JsString) -> Result<JsValue, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    type Dirent;
+
+    #[wasm_bindgen(method, getter)]
+    fn name(this: &Dirent) -> String;
+
+    #[wasm_bindgen(method, js_name = isDirectory)]
+    fn is_directory(this: &Dirent) -> bool;
+
+    #[wasm_bindgen(method, js_name = isFile)]
+    fn is_file(this: &Dirent) -> bool;
+
+    type Stats;
+
+    #[wasm_bindgen(method, getter)]
+    fn size(this: &Stats) -> f64;
+}
+
+/// Converts an exception thrown by `node:fs` into an `std::io::Error`,
+/// keeping `NotFound` so callers can tell a missing path apart.
+fn io_error(e: JsValue) -> std::io::Error {
+    let code = Reflect::get(&e, &JsValue::from_str("code"))
+        .ok()
+        .and_then(|c| c.as_string());
+
+    let kind = match code.as_deref() {
+        Some("ENOENT") => std::io::ErrorKind::NotFound,
+        _ => std::io::ErrorKind::Other,
+    };
+
+    std::io::Error::new(kind, Error(format!("{:?}", e)))
+}
+
+fn js_path(path: &std::path::Path) -> Result<JsString, std::io::Error> {
+    match path.to_str() {
+        Some(p) => Ok(JsString::from(p)),
+        None => Err(std::io::ErrorKind::InvalidInput.into()),
+    }
 }
 
 struct NodeFetch;
@@ -48,20 +95,60 @@ impl Fetch for NodeFetch {
         path: PathBuf,
     ) -> Pin<Box<dyn Future<Output = Result<String, std::io::Error>>>> {
         let fut = async move {
-            let path = match path.to_str() {
-                Some(p) => JsString::from(p),
-                None => return Err(std::io::ErrorKind::InvalidInput.into()),
-            };
-
+            let path = js_path(&path)?;
             let encoding = JsString::from("utf-8");
 
             match read_file(&path, &encoding).await {
                 Ok(o) => Ok(o.as_string().unwrap()),
-                Err(e) => {
-                    let txt = format!("{:?}", e);
-                    Err(std::io::Error::new(std::io::ErrorKind::Other, Error(txt)))
+                Err(e) => Err(io_error(e)),
+            }
+        };
+
+        Box::pin(fut)
+    }
+
+    fn list(
+        &self,
+        path: PathBuf,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Entry>, std::io::Error>>>> {
+        let fut = async move {
+            let options = Object::new();
+            Reflect::set(
+                &options,
+                &JsValue::from_str("withFileTypes"),
+                &JsValue::TRUE,
+            )
+            .unwrap();
+
+            let mut output = Vec::new();
+            let mut pending = vec![PathBuf::new()];
+
+            while let Some(relative) = pending.pop() {
+                let directory = js_path(&path.join(&relative))?;
+                let entries = read_dir(&directory, &options).await.map_err(io_error)?;
+
+                for dirent in Array::from(&entries).iter() {
+                    let dirent: Dirent = dirent.unchecked_into();
+                    let child = relative.join(dirent.name());
+
+                    if dirent.is_directory() {
+                        pending.push(child);
+                    } else if dirent.is_file() {
+                        let stats: Stats = stat(&js_path(&path.join(&child))?)
+                            .await
+                            .map_err(io_error)?
+                            .unchecked_into();
+
+                        output.push(Entry {
+                            path: child,
+                            len: stats.size() as u64,
+                        });
+                    }
                 }
             }
+
+            output.sort_by(|a, b| a.path.cmp(&b.path));
+            Ok(output)
         };
 
         Box::pin(fut)
@@ -0,0 +1,96 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Machine-readable index of proposals, built from their preambles.
+
+use chrono::NaiveDate;
+
+use eipw_preamble::{OwnedPreamble, SourceError};
+
+use serde::{Deserialize, Serialize};
+
+use snafu::{OptionExt, Snafu};
+
+#[derive(Snafu, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[snafu(context(false), display("{}", source))]
+    Source { source: SourceError },
+    #[snafu(display("preamble header `{}` is missing", name))]
+    Missing { name: &'static str },
+    #[snafu(display("preamble header `{}` must be {}, not `{}`", name, expected, value))]
+    Invalid {
+        name: &'static str,
+        expected: &'static str,
+        value: String,
+    },
+}
+
+/// One proposal in a catalog.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub number: u64,
+    pub title: String,
+    pub status: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub category: Option<String>,
+    pub authors: Vec<String>,
+
+    /// Date the proposal was created, formatted as `YYYY-MM-DD`.
+    pub created: String,
+
+    pub requires: Vec<u64>,
+}
+
+fn list(value: &str) -> impl '_ + Iterator<Item = &str> {
+    value.split(',').map(str::trim).filter(|i| !i.is_empty())
+}
+
+fn number(name: &'static str, value: &str) -> Result<u64, Error> {
+    value.parse().ok().context(InvalidSnafu {
+        name,
+        expected: "a non-negative integer",
+        value,
+    })
+}
+
+impl Entry {
+    /// Reads the preamble of `source` (a complete proposal.)
+    pub fn parse(source: &str) -> Result<Self, Error> {
+        let (preamble, _) = OwnedPreamble::from_source(source)?;
+
+        let optional = |name| preamble.by_name(name).map(|f| f.value());
+        let required = |name| optional(name).context(MissingSnafu { name });
+
+        let created = required("created")?;
+        if NaiveDate::parse_from_str(created, "%Y-%m-%d").is_err() {
+            return InvalidSnafu {
+                name: "created",
+                expected: "a date (`YYYY-MM-DD`)",
+                value: created,
+            }
+            .fail();
+        }
+
+        let requires = list(optional("requires").unwrap_or_default())
+            .map(|r| number("requires", r))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            number: number("eip", required("eip")?)?,
+            title: required("title")?.to_owned(),
+            status: required("status")?.to_owned(),
+            type_: required("type")?.to_owned(),
+            category: optional("category").map(str::to_owned),
+            authors: list(optional("author").unwrap_or_default())
+                .map(str::to_owned)
+                .collect(),
+            created: created.to_owned(),
+            requires,
+        })
+    }
+}
@@ -7,13 +7,35 @@
 #[cfg(feature = "tokio")]
 pub mod tokio;
 
+use std::cell::RefCell;
+use std::collections::BTreeSet;
 use std::future::Future;
 use std::path::PathBuf;
 use std::pin::Pin;
+use std::rc::Rc;
+
+/// A file found while listing a directory with [`Fetch::list`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Location of the file, relative to the listed directory.
+    pub path: PathBuf,
+
+    /// Size of the file, in bytes.
+    pub len: u64,
+}
 
 pub trait Fetch {
     fn fetch(&self, path: PathBuf)
         -> Pin<Box<dyn Future<Output = Result<String, std::io::Error>>>>;
+
+    /// Recursively list the files contained in the directory at `path`.
+    fn list(
+        &self,
+        _path: PathBuf,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Entry>, std::io::Error>>>> {
+        let fut = async { Err(std::io::ErrorKind::Unsupported.into()) };
+        Box::pin(fut)
+    }
 }
 
 #[derive(Debug, Default)]
@@ -29,6 +51,62 @@ impl Fetch for Null {
     }
 }
 
+/// Wraps another [`Fetch`], remembering every path it is asked to fetch or
+/// list.
+///
+/// Clones share the same record, so one clone can be given to a
+/// [`Linter`](crate::Linter) while another is kept to inspect the paths
+/// afterwards.
+#[derive(Debug, Default)]
+pub struct Recording<F> {
+    inner: Rc<F>,
+    paths: Rc<RefCell<BTreeSet<PathBuf>>>,
+}
+
+impl<F> Clone for Recording<F> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            paths: self.paths.clone(),
+        }
+    }
+}
+
+impl<F> Recording<F> {
+    pub fn new(inner: F) -> Self {
+        Self {
+            inner: Rc::new(inner),
+            paths: Default::default(),
+        }
+    }
+
+    /// Paths requested so far, including the listed directories.
+    pub fn paths(&self) -> BTreeSet<PathBuf> {
+        self.paths.borrow().clone()
+    }
+}
+
+impl<F> Fetch for Recording<F>
+where
+    F: Fetch,
+{
+    fn fetch(
+        &self,
+        path: PathBuf,
+    ) -> Pin<Box<dyn Future<Output = Result<String, std::io::Error>>>> {
+        self.paths.borrow_mut().insert(path.clone());
+        self.inner.fetch(path)
+    }
+
+    fn list(
+        &self,
+        path: PathBuf,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Entry>, std::io::Error>>>> {
+        self.paths.borrow_mut().insert(path.clone());
+        self.inner.list(path)
+    }
+}
+
 #[cfg(feature = "tokio")]
 pub use self::tokio::Tokio as DefaultFetch;
 
@@ -2,7 +2,7 @@ use std::future::Future;
 use std::path::PathBuf;
 use std::pin::Pin;
 
-use super::Fetch;
+use super::{Entry, Fetch};
 
 #[derive(Debug, Default)]
 pub struct Tokio;
@@ -16,6 +16,38 @@ impl Fetch for Tokio {
         let fut = async { tokio::fs::read_to_string(path).await };
         Box::pin(fut)
     }
+
+    fn list(
+        &self,
+        path: PathBuf,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Entry>, std::io::Error>>>> {
+        let fut = async move {
+            let mut output = Vec::new();
+            let mut pending = vec![PathBuf::new()];
+
+            while let Some(relative) = pending.pop() {
+                let mut entries = tokio::fs::read_dir(path.join(&relative)).await?;
+
+                while let Some(entry) = entries.next_entry().await? {
+                    let metadata = tokio::fs::metadata(entry.path()).await?;
+                    let child = relative.join(entry.file_name());
+
+                    if metadata.is_dir() {
+                        pending.push(child);
+                    } else if metadata.is_file() {
+                        output.push(Entry {
+                            path: child,
+                            len: metadata.len(),
+                        });
+                    }
+                }
+            }
+
+            output.sort_by(|a, b| a.path.cmp(&b.path));
+            Ok(output)
+        };
+        Box::pin(fut)
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -0,0 +1,53 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use std::ops::Range;
+
+/// A machine-applicable change to the source of a proposal, suggested by a
+/// lint through [`crate::lints::Context::fix`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fix {
+    /// The proposal the fix applies to, as given to the linter.
+    pub origin: Option<String>,
+
+    /// Byte offsets of the text to replace, within the entire source
+    /// (including the preamble.)
+    pub range: Range<usize>,
+
+    /// Text to insert in place of `range`.
+    pub replacement: String,
+}
+
+/// Applies `fixes` to `source`.
+///
+/// Fixes are applied in order of their position. A fix overlapping one
+/// already applied is skipped, and returned so it can be retried against the
+/// updated source.
+pub fn apply<'a>(source: &str, fixes: &'a [Fix]) -> (String, Vec<&'a Fix>) {
+    let mut sorted: Vec<_> = fixes.iter().collect();
+    sorted.sort_by_key(|f| (f.range.start, f.range.end));
+
+    let mut output = String::with_capacity(source.len());
+    let mut skipped = vec![];
+    let mut cursor = 0;
+
+    for fix in sorted {
+        if fix.range.start < cursor || fix.range.end > source.len() {
+            skipped.push(fix);
+            continue;
+        }
+
+        output.push_str(&source[cursor..fix.range.start]);
+        output.push_str(&fix.replacement);
+        cursor = fix.range.end;
+    }
+
+    output.push_str(&source[cursor..]);
+
+    (output, skipped)
+}
@@ -0,0 +1,144 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Rewrites proposals into the canonical form described by some of the lints
+//! (`preamble-order`, `preamble-trim`, `preamble-list-*`, and
+//! `markdown-headings-space`.)
+
+use comrak::nodes::NodeValue;
+use comrak::Arena;
+
+use crate::comrak_options;
+use crate::lints::DefaultLint;
+
+use eipw_preamble::OwnedPreamble;
+
+use regex::Regex;
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashSet;
+
+pub use eipw_preamble::SourceError as Error;
+
+/// Canonical form of a proposal.
+///
+/// Preamble values are always trimmed, and a space is always inserted between
+/// the `#` characters and the text of a heading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Formatter<S> {
+    /// Preamble headers in the order they should appear. Headers not listed
+    /// here keep their position.
+    pub order: Vec<S>,
+
+    /// Preamble headers containing comma-separated lists.
+    pub lists: Vec<S>,
+}
+
+impl<S> Default for Formatter<S> {
+    fn default() -> Self {
+        Self {
+            order: Vec::new(),
+            lists: Vec::new(),
+        }
+    }
+}
+
+impl<S> Formatter<S>
+where
+    S: Clone,
+{
+    /// Builds a formatter from the configuration of the `preamble::Order` and
+    /// `preamble::List` lints in `lints`.
+    pub fn from_lints<'l, I>(lints: I) -> Self
+    where
+        I: IntoIterator<Item = &'l DefaultLint<S>>,
+        S: 'l,
+    {
+        let mut formatter = Self::default();
+
+        for lint in lints {
+            match lint {
+                DefaultLint::PreambleOrder { names } => formatter.order = names.0.clone(),
+                DefaultLint::PreambleList { name } => formatter.lists.push(name.0.clone()),
+                _ => (),
+            }
+        }
+
+        formatter
+    }
+}
+
+impl<S> Formatter<S>
+where
+    S: AsRef<str>,
+{
+    /// Returns `source` in canonical form.
+    pub fn format(&self, source: &str) -> Result<String, Error> {
+        let (preamble, body_source) = OwnedPreamble::from_source(source)?;
+
+        let mut output = String::with_capacity(source.len());
+        output.push_str("---\n");
+        output.push_str(&self.preamble(preamble).to_string());
+        output.push_str("\n---\n");
+        output.push_str(&self.body(body_source));
+
+        Ok(output)
+    }
+
+    fn preamble(&self, mut preamble: OwnedPreamble) -> OwnedPreamble {
+        preamble.reorder(&self.order);
+
+        for field in preamble.fields_mut() {
+            let mut value = field.value().to_owned();
+
+            if self.lists.iter().any(|l| l.as_ref() == field.name()) {
+                value = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|i| !i.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+            }
+
+            field.set_value(value);
+        }
+
+        preamble
+    }
+
+    fn body(&self, body: &str) -> String {
+        let heading = Regex::new(r"^(#{1,6})([^#\s])").unwrap();
+
+        // Without the space, a heading is parsed as a paragraph. Only the
+        // first line of a top-level paragraph can be one, which rules out
+        // continuation lines, code blocks, and HTML blocks.
+        let arena = Arena::new();
+        let root = comrak::parse_document(&arena, body, &comrak_options());
+
+        let starts: HashSet<_> = root
+            .descendants()
+            .filter_map(|node| {
+                let data = node.data.borrow();
+                match data.value {
+                    NodeValue::Paragraph if data.sourcepos.start.column == 1 => {
+                        Some(data.sourcepos.start.line)
+                    }
+                    _ => None,
+                }
+            })
+            .collect();
+
+        body.split('\n')
+            .enumerate()
+            .map(|(idx, line)| match starts.contains(&(idx + 1)) {
+                true => heading.replace(line, "$1 $2"),
+                false => line.into(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,258 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Dependency graph of proposals, built from `requires` headers and
+//! (optionally) references in their bodies.
+
+use comrak::Arena;
+
+use crate::catalog::Entry;
+use crate::comrak_options;
+use crate::lints::markdown::proposal_ref::referenced_numbers;
+use crate::lints::Error;
+
+use serde::{Serialize, Serializer};
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Write;
+
+/// How one proposal depends on another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EdgeKind {
+    /// Listed in the `requires` header.
+    Requires,
+
+    /// Mentioned in the body (as found by `markdown-refs`.)
+    Reference,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Node {
+    pub number: u64,
+    pub title: String,
+    pub status: String,
+    pub category: Option<String>,
+}
+
+impl Node {
+    fn label(&self) -> String {
+        let prefix = match self.category.as_deref() {
+            Some("ERC") => "ERC",
+            _ => "EIP",
+        };
+
+        format!("{}-{}: {}", prefix, self.number, self.title)
+    }
+}
+
+/// An edge pointing from a proposal to one of its dependencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Edge {
+    pub from: u64,
+    pub to: u64,
+    pub kind: EdgeKind,
+}
+
+fn values<S, K, V>(map: &BTreeMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    V: Serialize,
+{
+    serializer.collect_seq(map.values())
+}
+
+/// Fill colour for proposals with the given `status`.
+fn colour(status: &str) -> &'static str {
+    match status {
+        "Final" | "Living" => "palegreen",
+        "Last Call" => "gold",
+        "Review" => "lightskyblue",
+        "Draft" => "lightgrey",
+        "Stagnant" => "orange",
+        "Withdrawn" => "salmon",
+        _ => "white",
+    }
+}
+
+/// Proposals and the dependencies between them.
+///
+/// Edges may point at proposals that were never inserted (eg. a `requires`
+/// entry for a proposal outside the catalogued directory.)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Graph {
+    #[serde(serialize_with = "values")]
+    nodes: BTreeMap<u64, Node>,
+    edges: BTreeSet<Edge>,
+}
+
+impl Graph {
+    /// Adds the proposal described by `entry`, and its `requires` edges.
+    pub fn insert(&mut self, entry: &Entry) {
+        self.nodes.insert(
+            entry.number,
+            Node {
+                number: entry.number,
+                title: entry.title.clone(),
+                status: entry.status.clone(),
+                category: entry.category.clone(),
+            },
+        );
+
+        for required in &entry.requires {
+            self.edges.insert(Edge {
+                from: entry.number,
+                to: *required,
+                kind: EdgeKind::Requires,
+            });
+        }
+    }
+
+    /// Adds edges for proposals mentioned in `body`, the markdown of proposal
+    /// `number` (excluding its preamble), unless they're already required.
+    pub fn insert_references(&mut self, number: u64, body: &str) -> Result<(), Error> {
+        let arena = Arena::new();
+        let root = comrak::parse_document(&arena, body, &comrak_options());
+
+        for to in referenced_numbers(root)? {
+            let requires = Edge {
+                from: number,
+                to,
+                kind: EdgeKind::Requires,
+            };
+
+            if to == number || self.edges.contains(&requires) {
+                continue;
+            }
+
+            self.edges.insert(Edge {
+                kind: EdgeKind::Reference,
+                ..requires
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn nodes(&self) -> impl '_ + Iterator<Item = &Node> {
+        self.nodes.values()
+    }
+
+    pub fn edges(&self) -> impl '_ + Iterator<Item = &Edge> {
+        self.edges.iter()
+    }
+
+    /// Subgraph of nodes reachable from `start`, following each edge from the
+    /// first number returned by `direction` to the second.
+    fn closure<F>(&self, start: u64, direction: F) -> Self
+    where
+        F: Fn(&Edge) -> (u64, u64),
+    {
+        let mut seen = BTreeSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(current) = queue.pop_front() {
+            for edge in &self.edges {
+                let (from, to) = direction(edge);
+
+                if from == current && seen.insert(to) {
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        Self {
+            nodes: self
+                .nodes
+                .iter()
+                .filter(|(n, _)| seen.contains(n))
+                .map(|(n, node)| (*n, node.clone()))
+                .collect(),
+            edges: self
+                .edges
+                .iter()
+                .filter(|e| seen.contains(&e.from) && seen.contains(&e.to))
+                .copied()
+                .collect(),
+        }
+    }
+
+    /// Subgraph of everything `number` depends on, directly or transitively.
+    pub fn deps_of(&self, number: u64) -> Self {
+        self.closure(number, |e| (e.from, e.to))
+    }
+
+    /// Subgraph of everything that depends on `number`, directly or
+    /// transitively.
+    pub fn dependents_of(&self, number: u64) -> Self {
+        self.closure(number, |e| (e.to, e.from))
+    }
+
+    /// Renders the graph in Graphviz's DOT language.
+    pub fn to_dot(&self) -> String {
+        let mut output = String::from("digraph {\n");
+
+        for node in self.nodes.values() {
+            writeln!(
+                output,
+                "    {} [label=\"{}\", style=filled, fillcolor={}];",
+                node.number,
+                node.label().replace('\\', "\\\\").replace('"', "\\\""),
+                colour(&node.status),
+            )
+            .unwrap();
+        }
+
+        for edge in &self.edges {
+            let style = match edge.kind {
+                EdgeKind::Requires => "",
+                EdgeKind::Reference => " [style=dashed]",
+            };
+            writeln!(output, "    {} -> {}{};", edge.from, edge.to, style).unwrap();
+        }
+
+        output.push_str("}\n");
+        output
+    }
+
+    /// Renders the graph as a Mermaid flowchart.
+    pub fn to_mermaid(&self) -> String {
+        let mut output = String::from("flowchart LR\n");
+
+        for node in self.nodes.values() {
+            writeln!(
+                output,
+                "    n{}[\"{}\"]",
+                node.number,
+                node.label().replace('"', "#quot;"),
+            )
+            .unwrap();
+        }
+
+        for edge in &self.edges {
+            let arrow = match edge.kind {
+                EdgeKind::Requires => "-->",
+                EdgeKind::Reference => "-.->",
+            };
+            writeln!(output, "    n{} {} n{}", edge.from, arrow, edge.to).unwrap();
+        }
+
+        let mut by_colour: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for node in self.nodes.values() {
+            by_colour
+                .entry(colour(&node.status))
+                .or_default()
+                .push(format!("n{}", node.number));
+        }
+
+        for (colour, nodes) in by_colour {
+            writeln!(output, "    classDef {0} fill:{0}", colour).unwrap();
+            writeln!(output, "    class {} {}", nodes.join(","), colour).unwrap();
+        }
+
+        output
+    }
+}
@@ -4,10 +4,16 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+pub mod catalog;
 pub mod fetch;
+pub mod fix;
+pub mod format;
+pub mod graph;
 pub mod lints;
 pub mod modifiers;
+pub mod renumber;
 pub mod reporters;
+pub mod scaffold;
 pub mod tree;
 
 use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet};
@@ -30,6 +36,7 @@ use snafu::{ensure, ResultExt, Snafu};
 
 use std::cell::RefCell;
 use std::collections::hash_map::{self, HashMap};
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
 #[derive(Snafu, Debug)]
@@ -409,6 +416,7 @@ pub fn default_lints_enum() -> impl Iterator<Item = (&'static str, DefaultLint<&
                 mode: markdown::regex::Mode::Excludes,
                 pattern: r"(?i)erc[\s]*[0-9]+",
                 message: "proposals must be referenced with the form `ERC-N` (not `ERCN` or `ERC N`)",
+                sections: None,
             }),
         ),
         (
@@ -417,6 +425,7 @@ pub fn default_lints_enum() -> impl Iterator<Item = (&'static str, DefaultLint<&
                 mode: markdown::regex::Mode::Excludes,
                 pattern: r"(?i)eip[\s]*[0-9]+",
                 message: "proposals must be referenced with the form `EIP-N` (not `EIPN` or `EIP N`)",
+                sections: None,
             }),
         ),
         (
@@ -737,6 +746,8 @@ where
 
         let mut to_check = Vec::with_capacity(self.sources.len());
         let mut fetched_eips = HashMap::new();
+        let mut fetched_listings = HashMap::new();
+        let mut listed_eips = HashSet::new();
 
         for source in self.sources {
             let source_origin = source.origin().map(Path::to_path_buf);
@@ -759,6 +770,8 @@ where
                     body: inner.body,
                     preamble: &inner.preamble,
                     eips: Default::default(),
+                    listings: Default::default(),
+                    directories: Default::default(),
                 };
 
                 lint.1
@@ -768,6 +781,9 @@ where
                     })?;
 
                 let eips = context.eips.into_inner();
+                let mut listings = context.listings.into_inner();
+                let directories = context.directories.into_inner();
+                listings.extend(directories.iter().cloned());
 
                 // For now, string sources shouldn't be allowed to fetch external
                 // resources. The origin field isn't guaranteed to be a file/URL,
@@ -781,12 +797,26 @@ where
                     }
                 );
 
-                for eip in eips.into_iter() {
-                    let root = match source {
-                        Source::File(p) => p.parent().unwrap_or_else(|| Path::new(".")),
-                        _ => unreachable!(),
+                let root = root(source.origin());
+
+                for listing in listings.into_iter() {
+                    let path = root.join(listing);
+
+                    let entry = match fetched_listings.entry(path) {
+                        hash_map::Entry::Occupied(_) => continue,
+                        hash_map::Entry::Vacant(v) => v,
                     };
 
+                    // Listing is optional, so string sources get an error the
+                    // lints can ignore, instead of failing the whole run.
+                    let content = match source.is_string() {
+                        true => Err(std::io::ErrorKind::Unsupported.into()),
+                        false => self.fetch.list(entry.key().clone()).await,
+                    };
+                    entry.insert(content);
+                }
+
+                for eip in eips.into_iter() {
                     let path = root.join(eip);
 
                     let entry = match fetched_eips.entry(path) {
@@ -797,6 +827,43 @@ where
                     let content = Source::File(entry.key()).fetch(&*self.fetch).await;
                     entry.insert(content);
                 }
+
+                for directory in directories.into_iter() {
+                    let directory = root.join(directory);
+
+                    let entries = match fetched_listings.get(&directory) {
+                        Some(Ok(e)) => e,
+                        _ => continue,
+                    };
+
+                    let proposals = entries.iter().filter(|e| {
+                        e.path.components().count() == 1
+                            && e.path.extension().map_or(false, |x| x == "md")
+                    });
+
+                    for proposal in proposals {
+                        let path = directory.join(&proposal.path);
+
+                        let entry = match fetched_eips.entry(path) {
+                            hash_map::Entry::Occupied(_) => continue,
+                            hash_map::Entry::Vacant(v) => v,
+                        };
+
+                        let content = match Source::File(entry.key()).fetch(&*self.fetch).await {
+                            Ok(c) if Preamble::split(&c).is_err() => Err(Error::Io {
+                                path: entry.key().clone(),
+                                source: std::io::Error::new(
+                                    std::io::ErrorKind::InvalidData,
+                                    "missing preamble",
+                                ),
+                            }),
+                            other => other,
+                        };
+
+                        listed_eips.insert(entry.key().clone());
+                        entry.insert(content);
+                    }
+                }
             }
         }
 
@@ -812,7 +879,14 @@ where
                 }
             };
 
-            let inner = match process(&self.reporter, &resources_arena, None, source)? {
+            // Problems in proposals fetched only because they were in a
+            // directory aren't interesting enough to report.
+            let reporter: &dyn Reporter = match listed_eips.contains(origin) {
+                true => &reporters::Null,
+                false => &self.reporter,
+            };
+
+            let inner = match process(reporter, &resources_arena, None, source)? {
                 Some(s) => s,
                 None => return Ok(self.reporter),
             };
@@ -842,6 +916,7 @@ where
                     inner: inner.clone(),
                     reporter: &self.reporter,
                     eips: &parsed_eips,
+                    listings: &fetched_listings,
                     annotation_type: settings.default_annotation_type,
                 };
 
@@ -854,6 +929,7 @@ where
                     inner: inner.clone(),
                     reporter: &self.reporter,
                     eips: &parsed_eips,
+                    listings: &fetched_listings,
                     annotation_type,
                 };
 
@@ -867,6 +943,27 @@ where
     }
 }
 
+/// Directory that paths referenced by the proposal at `origin` are relative
+/// to.
+pub(crate) fn root(origin: Option<&Path>) -> &Path {
+    origin
+        .and_then(Path::parent)
+        .unwrap_or_else(|| Path::new("."))
+}
+
+/// Markdown extensions enabled when parsing proposals.
+pub(crate) fn comrak_options() -> ComrakOptions {
+    ComrakOptions {
+        extension: ComrakExtensionOptions {
+            table: true,
+            autolink: true,
+            footnotes: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
 fn process<'a>(
     reporter: &dyn Reporter,
     arena: &'a Arena<Node<'a, RefCell<Ast>>>,
@@ -942,20 +1039,10 @@ fn process<'a>(
         }
     };
 
-    let options = ComrakOptions {
-        extension: ComrakExtensionOptions {
-            table: true,
-            autolink: true,
-            footnotes: true,
-            ..Default::default()
-        },
-        ..Default::default()
-    };
-
     let mut preamble_lines = preamble_source.matches('\n').count();
     preamble_lines += 3;
 
-    let body = comrak::parse_document(arena, body_source, &options);
+    let body = comrak::parse_document(arena, body_source, &comrak_options());
 
     for node in body.descendants() {
         let mut data = node.data.borrow_mut();
@@ -967,6 +1054,12 @@ fn process<'a>(
         } else {
             data.sourcepos.start.line += preamble_lines;
         }
+
+        // End lines are offset too, so lints that look at every line of a node
+        // (eg. to skip code blocks) see line numbers in the whole file.
+        if data.sourcepos.end.line != 0 {
+            data.sourcepos.end.line += preamble_lines;
+        }
     }
 
     Ok(Some(InnerContext {
@@ -982,6 +1075,27 @@ fn process<'a>(
 mod tests {
     use super::*;
 
+    #[test]
+    fn process_offsets_positions() {
+        use comrak::nodes::NodeValue;
+
+        let source = "---\neip: 1\ntitle: x\n---\n\n```\na\nb\n```\n";
+        let arena = Arena::new();
+        let inner = process(&reporters::Null, &arena, None, source)
+            .unwrap()
+            .unwrap();
+
+        let block = inner
+            .body
+            .descendants()
+            .find(|n| matches!(n.data.borrow().value, NodeValue::CodeBlock(_)))
+            .unwrap();
+
+        let sourcepos = block.data.borrow().sourcepos;
+        assert_eq!(sourcepos.start.line, 6);
+        assert_eq!(sourcepos.end.line, 9);
+    }
+
     #[test]
     fn lints_serialize_deserialize() {
         type DefaultLints<S> = HashMap<S, DefaultLint<S>>;
@@ -12,6 +12,8 @@ use annotate_snippets::snippet::{AnnotationType, Snippet};
 
 use comrak::nodes::AstNode;
 
+use crate::fetch::Entry;
+use crate::fix::Fix;
 use crate::reporters::{self, Reporter};
 
 use educe::Educe;
@@ -26,7 +28,8 @@ use std::cell::RefCell;
 use std::cmp::max;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
-use std::ops::Deref;
+use std::io::ErrorKind;
+use std::ops::{Deref, Range};
 use std::path::{Path, PathBuf};
 use std::string::FromUtf8Error;
 
@@ -78,6 +81,7 @@ where
 {
     pub(crate) inner: InnerContext<'a>,
     pub(crate) eips: &'b HashMap<&'b Path, Result<InnerContext<'b>, &'b crate::Error>>,
+    pub(crate) listings: &'b HashMap<PathBuf, Result<Vec<Entry>, std::io::Error>>,
     #[educe(Debug(ignore))]
     pub(crate) reporter: &'b dyn Reporter,
     pub(crate) annotation_type: AnnotationType,
@@ -116,6 +120,11 @@ where
             .join("\n")
     }
 
+    /// The entire source, including the preamble.
+    pub(crate) fn source(&self) -> &'a str {
+        self.inner.source
+    }
+
     pub fn body_source(&self) -> &'a str {
         self.inner.body_source
     }
@@ -137,15 +146,26 @@ where
         Ok(())
     }
 
-    pub fn eip(&self, path: &Path) -> Result<Context<'b, 'b>, &crate::Error> {
-        let origin = self
-            .origin()
-            .expect("lint attempted to access an external resource without having an origin");
+    /// Suggest replacing the bytes at `range` of the entire source (including
+    /// the preamble) with `replacement`.
+    pub fn fix<R>(&self, range: Range<usize>, replacement: R) -> Result<(), Error>
+    where
+        R: Into<String>,
+    {
+        self.reporter.fix(Fix {
+            origin: self.origin().map(str::to_owned),
+            range,
+            replacement: replacement.into(),
+        })?;
+        Ok(())
+    }
 
-        let origin_path = PathBuf::from(origin);
-        let root = origin_path.parent().unwrap_or_else(|| Path::new("."));
+    fn resolve(&self, path: &Path) -> PathBuf {
+        crate::root(self.origin().map(Path::new)).join(path)
+    }
 
-        let key = root.join(path);
+    pub fn eip(&self, path: &Path) -> Result<Context<'b, 'b>, &crate::Error> {
+        let key = self.resolve(path);
 
         let inner = match self.eips.get(key.as_path()) {
             Some(Ok(i)) => i,
@@ -156,10 +176,37 @@ where
         Ok(Context {
             inner: inner.clone(),
             eips: self.eips,
+            listings: self.listings,
             reporter: self.reporter,
             annotation_type: self.annotation_type,
         })
     }
+
+    /// Files contained in the directory at `path` (relative to this
+    /// proposal), as requested by [`FetchContext::list`].
+    pub fn listing(&self, path: &Path) -> Result<&'b [Entry], &'b std::io::Error> {
+        let key = self.resolve(path);
+
+        match self.listings.get(&key) {
+            Some(Ok(entries)) => Ok(entries),
+            Some(Err(e)) => Err(e),
+            None => panic!("no listing found for key `{}`", key.display()),
+        }
+    }
+
+    /// Like [`Context::listing`], but `Ok(None)` if the directory doesn't
+    /// exist, or can't be listed at all (eg. for sources without a path, or
+    /// with a [`crate::fetch::Fetch`] that doesn't implement `list`.)
+    pub(crate) fn existing_listing(
+        &self,
+        path: &Path,
+    ) -> Result<Option<&'b [Entry]>, &'b std::io::Error> {
+        match self.listing(path) {
+            Ok(entries) => Ok(Some(entries)),
+            Err(e) if matches!(e.kind(), ErrorKind::NotFound | ErrorKind::Unsupported) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -167,6 +214,8 @@ pub struct FetchContext<'a> {
     pub(crate) preamble: &'a Preamble<'a>,
     pub(crate) body: &'a AstNode<'a>,
     pub(crate) eips: RefCell<HashSet<PathBuf>>,
+    pub(crate) listings: RefCell<HashSet<PathBuf>>,
+    pub(crate) directories: RefCell<HashSet<PathBuf>>,
 }
 
 impl<'a> FetchContext<'a> {
@@ -181,6 +230,22 @@ impl<'a> FetchContext<'a> {
     pub fn fetch(&self, path: PathBuf) {
         self.eips.borrow_mut().insert(path);
     }
+
+    /// Request a recursive listing of the directory at `path`, for use with
+    /// [`Context::listing`].
+    pub fn list(&self, path: PathBuf) {
+        self.listings.borrow_mut().insert(path);
+    }
+
+    /// Request a listing of the directory at `path`, like [`FetchContext::list`],
+    /// and fetch every proposal (`.md` file) directly inside it for use with
+    /// [`Context::eip`].
+    ///
+    /// Files that can't be parsed as proposals are reported as errors from
+    /// [`Context::eip`] instead of to the reporter.
+    pub fn fetch_directory(&self, path: PathBuf) {
+        self.directories.borrow_mut().insert(path);
+    }
 }
 
 pub trait Lint: Debug {
@@ -51,21 +51,44 @@ pub enum DefaultLint<S> {
         name: preamble::Url<S>,
     },
 
+    MarkdownAddressChecksum(markdown::AddressChecksum),
+    MarkdownAssetsForeign(markdown::AssetsForeign<S>),
+    MarkdownAssetsNumber(markdown::AssetsNumber<S>),
+    MarkdownAssetsSize(markdown::AssetsSize<S>),
+    MarkdownAssetsUnused(markdown::AssetsUnused<S>),
+    MarkdownCharacters(markdown::Characters<S>),
+    MarkdownCodeBlockLanguage(markdown::CodeBlockLanguage<S>),
+    MarkdownCodeBlockSyntax(markdown::CodeBlockSyntax<S>),
+    MarkdownCopyright(markdown::Copyright<S>),
+    MarkdownFinalNewline(markdown::FinalNewline),
+    MarkdownFootnotes(markdown::Footnotes<S>),
+    MarkdownHardTabs(markdown::HardTabs),
+    MarkdownHtmlAllow(markdown::HtmlAllow<S>),
     MarkdownHtmlComments(markdown::HtmlComments<S>),
+    MarkdownImages(markdown::Images<S>),
+    MarkdownInterfaceId(markdown::InterfaceId<S>),
     MarkdownJsonSchema(markdown::JsonSchema<S>),
+    MarkdownLineEndings(markdown::LineEndings),
     MarkdownLinkFirst {
         pattern: markdown::LinkFirst<S>,
     },
     MarkdownLinkStatus(markdown::LinkStatus<S>),
+    MarkdownNonBreakingSpaces(markdown::NonBreakingSpaces),
     MarkdownProposalRef(markdown::ProposalRef<S>),
+    MarkdownProtocolCollision(markdown::ProtocolCollision<S>),
     MarkdownRegex(markdown::Regex<S>),
     MarkdownRelativeLinks(markdown::RelativeLinks<S>),
+    MarkdownRfc2119(markdown::Rfc2119<S>),
     MarkdownSectionOrder {
         sections: markdown::SectionOrder<S>,
     },
     MarkdownSectionRequired {
         sections: markdown::SectionRequired<S>,
     },
+    MarkdownSpell(markdown::Spell<S>),
+    MarkdownTableStructure(markdown::TableStructure),
+    MarkdownTrailingBlankLines(markdown::TrailingBlankLines),
+    MarkdownTrailingWhitespace(markdown::TrailingWhitespace),
     MarkdownHeadingsSpace(markdown::HeadingsSpace),
 }
 
@@ -100,15 +123,38 @@ where
             Self::PreambleUintList { name } => Box::new(name),
             Self::PreambleUrl { name } => Box::new(name),
 
+            Self::MarkdownAddressChecksum(l) => Box::new(l),
+            Self::MarkdownAssetsForeign(l) => Box::new(l),
+            Self::MarkdownAssetsNumber(l) => Box::new(l),
+            Self::MarkdownAssetsSize(l) => Box::new(l),
+            Self::MarkdownAssetsUnused(l) => Box::new(l),
+            Self::MarkdownCharacters(l) => Box::new(l),
+            Self::MarkdownCodeBlockLanguage(l) => Box::new(l),
+            Self::MarkdownCodeBlockSyntax(l) => Box::new(l),
+            Self::MarkdownCopyright(l) => Box::new(l),
+            Self::MarkdownFinalNewline(l) => Box::new(l),
+            Self::MarkdownFootnotes(l) => Box::new(l),
+            Self::MarkdownHardTabs(l) => Box::new(l),
+            Self::MarkdownHtmlAllow(l) => Box::new(l),
             Self::MarkdownHtmlComments(l) => Box::new(l),
+            Self::MarkdownImages(l) => Box::new(l),
+            Self::MarkdownInterfaceId(l) => Box::new(l),
             Self::MarkdownJsonSchema(l) => Box::new(l),
+            Self::MarkdownLineEndings(l) => Box::new(l),
             Self::MarkdownLinkFirst { pattern } => Box::new(pattern),
             Self::MarkdownLinkStatus(l) => Box::new(l),
+            Self::MarkdownNonBreakingSpaces(l) => Box::new(l),
             Self::MarkdownProposalRef(l) => Box::new(l),
+            Self::MarkdownProtocolCollision(l) => Box::new(l),
             Self::MarkdownRegex(l) => Box::new(l),
             Self::MarkdownRelativeLinks(l) => Box::new(l),
+            Self::MarkdownRfc2119(l) => Box::new(l),
             Self::MarkdownSectionOrder { sections } => Box::new(sections),
             Self::MarkdownSectionRequired { sections } => Box::new(sections),
+            Self::MarkdownSpell(l) => Box::new(l),
+            Self::MarkdownTableStructure(l) => Box::new(l),
+            Self::MarkdownTrailingBlankLines(l) => Box::new(l),
+            Self::MarkdownTrailingWhitespace(l) => Box::new(l),
             Self::MarkdownHeadingsSpace(l) => Box::new(l),
         }
     }
@@ -139,15 +185,38 @@ where
             Self::PreambleUintList { name } => name,
             Self::PreambleUrl { name } => name,
 
+            Self::MarkdownAddressChecksum(l) => l,
+            Self::MarkdownAssetsForeign(l) => l,
+            Self::MarkdownAssetsNumber(l) => l,
+            Self::MarkdownAssetsSize(l) => l,
+            Self::MarkdownAssetsUnused(l) => l,
+            Self::MarkdownCharacters(l) => l,
+            Self::MarkdownCodeBlockLanguage(l) => l,
+            Self::MarkdownCodeBlockSyntax(l) => l,
+            Self::MarkdownCopyright(l) => l,
+            Self::MarkdownFinalNewline(l) => l,
+            Self::MarkdownFootnotes(l) => l,
+            Self::MarkdownHardTabs(l) => l,
+            Self::MarkdownHtmlAllow(l) => l,
             Self::MarkdownHtmlComments(l) => l,
+            Self::MarkdownImages(l) => l,
+            Self::MarkdownInterfaceId(l) => l,
             Self::MarkdownJsonSchema(l) => l,
+            Self::MarkdownLineEndings(l) => l,
             Self::MarkdownLinkFirst { pattern } => pattern,
             Self::MarkdownLinkStatus(l) => l,
+            Self::MarkdownNonBreakingSpaces(l) => l,
             Self::MarkdownProposalRef(l) => l,
+            Self::MarkdownProtocolCollision(l) => l,
             Self::MarkdownRegex(l) => l,
             Self::MarkdownRelativeLinks(l) => l,
+            Self::MarkdownRfc2119(l) => l,
             Self::MarkdownSectionOrder { sections } => sections,
             Self::MarkdownSectionRequired { sections } => sections,
+            Self::MarkdownSpell(l) => l,
+            Self::MarkdownTableStructure(l) => l,
+            Self::MarkdownTrailingBlankLines(l) => l,
+            Self::MarkdownTrailingWhitespace(l) => l,
             Self::MarkdownHeadingsSpace(l) => l,
         }
     }
@@ -241,12 +310,99 @@ where
                 name: preamble::Url(name.0.as_ref()),
             },
 
+            Self::MarkdownAddressChecksum(l) => DefaultLint::MarkdownAddressChecksum(l.clone()),
+            Self::MarkdownAssetsForeign(l) => {
+                DefaultLint::MarkdownAssetsForeign(markdown::AssetsForeign {
+                    name: l.name.as_ref(),
+                    assets: l.assets.as_ref(),
+                    prefix: l.prefix.as_ref(),
+                    suffix: l.suffix.as_ref(),
+                })
+            }
+            Self::MarkdownAssetsNumber(l) => {
+                DefaultLint::MarkdownAssetsNumber(markdown::AssetsNumber {
+                    name: l.name.as_ref(),
+                    assets: l.assets.as_ref(),
+                    prefix: l.prefix.as_ref(),
+                    suffix: l.suffix.as_ref(),
+                })
+            }
+            Self::MarkdownAssetsSize(l) => DefaultLint::MarkdownAssetsSize(markdown::AssetsSize {
+                name: l.name.as_ref(),
+                assets: l.assets.as_ref(),
+                prefix: l.prefix.as_ref(),
+                max: l.max,
+                exempt: l.exempt.iter().map(AsRef::as_ref).collect(),
+            }),
+            Self::MarkdownAssetsUnused(l) => {
+                DefaultLint::MarkdownAssetsUnused(markdown::AssetsUnused {
+                    name: l.name.as_ref(),
+                    assets: l.assets.as_ref(),
+                    prefix: l.prefix.as_ref(),
+                })
+            }
+            Self::MarkdownCharacters(l) => DefaultLint::MarkdownCharacters(markdown::Characters {
+                allow: l.allow.iter().map(AsRef::as_ref).collect(),
+                fields: l
+                    .fields
+                    .iter()
+                    .map(|f| markdown::characters::FieldCharacters {
+                        name: f.name.as_ref(),
+                        ascii: f.ascii,
+                        allow: f.allow.iter().map(AsRef::as_ref).collect(),
+                    })
+                    .collect(),
+            }),
+            Self::MarkdownCodeBlockLanguage(l) => {
+                DefaultLint::MarkdownCodeBlockLanguage(markdown::CodeBlockLanguage {
+                    allowed: l.allowed.iter().map(AsRef::as_ref).collect(),
+                })
+            }
+            Self::MarkdownCodeBlockSyntax(l) => {
+                DefaultLint::MarkdownCodeBlockSyntax(markdown::CodeBlockSyntax {
+                    json: l.json.iter().map(AsRef::as_ref).collect(),
+                    toml: l.toml.iter().map(AsRef::as_ref).collect(),
+                    yaml: l.yaml.iter().map(AsRef::as_ref).collect(),
+                })
+            }
+            Self::MarkdownCopyright(l) => DefaultLint::MarkdownCopyright(markdown::Copyright {
+                section: l.section.as_ref(),
+                template: l.template.as_ref(),
+                license: l.license.as_ref(),
+            }),
+            Self::MarkdownFinalNewline(l) => DefaultLint::MarkdownFinalNewline(l.clone()),
+            Self::MarkdownFootnotes(l) => DefaultLint::MarkdownFootnotes(markdown::Footnotes {
+                section: l.section.as_ref().map(AsRef::as_ref),
+            }),
+            Self::MarkdownHardTabs(l) => DefaultLint::MarkdownHardTabs(l.clone()),
+            Self::MarkdownHtmlAllow(l) => DefaultLint::MarkdownHtmlAllow(markdown::HtmlAllow {
+                tags: l
+                    .tags
+                    .iter()
+                    .map(|t| markdown::html_allow::AllowedTag {
+                        name: t.name.as_ref(),
+                        attributes: t.attributes.iter().map(AsRef::as_ref).collect(),
+                    })
+                    .collect(),
+            }),
             Self::MarkdownHtmlComments(l) => {
                 DefaultLint::MarkdownHtmlComments(markdown::HtmlComments {
                     name: l.name.as_ref(),
                     warn_for: l.warn_for.iter().map(AsRef::as_ref).collect(),
                 })
             }
+            Self::MarkdownImages(l) => DefaultLint::MarkdownImages(markdown::Images {
+                name: l.name.as_ref(),
+                assets: l.assets.as_ref(),
+                prefix: l.prefix.as_ref(),
+                formats: l.formats.iter().map(AsRef::as_ref).collect(),
+                max: l.max,
+            }),
+            Self::MarkdownInterfaceId(l) => {
+                DefaultLint::MarkdownInterfaceId(markdown::InterfaceId {
+                    language: l.language.as_ref(),
+                })
+            }
             Self::MarkdownJsonSchema(l) => DefaultLint::MarkdownJsonSchema(markdown::JsonSchema {
                 help: l.help.as_ref(),
                 language: l.language.as_ref(),
@@ -257,6 +413,7 @@ where
                     .map(|(a, b)| (a.as_ref(), b.as_ref()))
                     .collect(),
             }),
+            Self::MarkdownLineEndings(l) => DefaultLint::MarkdownLineEndings(l.clone()),
             Self::MarkdownLinkFirst { pattern } => DefaultLint::MarkdownLinkFirst {
                 pattern: markdown::LinkFirst(pattern.0.as_ref()),
             },
@@ -270,28 +427,65 @@ where
                     .map(|v| v.iter().map(AsRef::as_ref).collect())
                     .collect(),
             }),
+            Self::MarkdownNonBreakingSpaces(l) => DefaultLint::MarkdownNonBreakingSpaces(l.clone()),
             Self::MarkdownProposalRef(l) => {
                 DefaultLint::MarkdownProposalRef(markdown::ProposalRef {
                     prefix: l.prefix.as_ref(),
                     suffix: l.suffix.as_ref(),
                 })
             }
+            Self::MarkdownProtocolCollision(l) => {
+                DefaultLint::MarkdownProtocolCollision(markdown::ProtocolCollision {
+                    proposals: l.proposals.as_ref(),
+                    category: l.category.as_ref(),
+                    ignore_status: l.ignore_status.iter().map(AsRef::as_ref).collect(),
+                    claims: l
+                        .claims
+                        .iter()
+                        .map(|c| markdown::protocol_collision::Claim {
+                            kind: c.kind.as_ref(),
+                            column: c.column.as_ref().map(AsRef::as_ref),
+                            heading: c.heading.as_ref().map(AsRef::as_ref),
+                            value: c.value.as_ref(),
+                        })
+                        .collect(),
+                })
+            }
             Self::MarkdownRegex(l) => DefaultLint::MarkdownRegex(markdown::Regex {
                 message: l.message.as_ref(),
                 mode: l.mode,
                 pattern: l.pattern.as_ref(),
+                sections: l
+                    .sections
+                    .as_ref()
+                    .map(|v| v.iter().map(AsRef::as_ref).collect()),
             }),
             Self::MarkdownRelativeLinks(l) => {
                 DefaultLint::MarkdownRelativeLinks(markdown::RelativeLinks {
                     exceptions: l.exceptions.iter().map(AsRef::as_ref).collect(),
                 })
             }
+            Self::MarkdownRfc2119(l) => DefaultLint::MarkdownRfc2119(markdown::Rfc2119 {
+                boilerplate: l.boilerplate.as_ref(),
+                normative: l.normative.iter().map(AsRef::as_ref).collect(),
+                non_normative: l.non_normative.iter().map(AsRef::as_ref).collect(),
+            }),
             Self::MarkdownSectionOrder { sections } => DefaultLint::MarkdownSectionOrder {
                 sections: markdown::SectionOrder(sections.0.iter().map(AsRef::as_ref).collect()),
             },
             Self::MarkdownSectionRequired { sections } => DefaultLint::MarkdownSectionRequired {
                 sections: markdown::SectionRequired(sections.0.iter().map(AsRef::as_ref).collect()),
             },
+            Self::MarkdownSpell(l) => DefaultLint::MarkdownSpell(markdown::Spell {
+                dictionary: l.dictionary.iter().map(AsRef::as_ref).collect(),
+            }),
+            Self::MarkdownTableStructure(l) => DefaultLint::MarkdownTableStructure(l.clone()),
+            Self::MarkdownTrailingBlankLines(l) => {
+                DefaultLint::MarkdownTrailingBlankLines(l.clone())
+            }
+            Self::MarkdownTrailingWhitespace(l) => {
+                DefaultLint::MarkdownTrailingWhitespace(l.clone())
+            }
             Self::MarkdownHeadingsSpace(l) => DefaultLint::MarkdownHeadingsSpace(l.clone()),
         }
     }
@@ -4,24 +4,54 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+pub mod address_checksum;
+pub mod assets;
+pub mod characters;
+pub mod code_blocks;
+pub mod copyright;
+pub mod footnotes;
 pub mod headings_space;
+pub mod html_allow;
 pub mod html_comments;
+pub mod images;
+pub mod interface_id;
 pub mod json_schema;
 pub mod link_first;
 pub mod link_status;
 pub mod proposal_ref;
+pub mod protocol_collision;
 pub mod regex;
 pub mod relative_links;
+pub mod rfc2119;
 pub mod section_order;
 pub mod section_required;
+pub mod spell;
+pub mod table_structure;
+pub mod whitespace;
 
+pub use self::address_checksum::AddressChecksum;
+pub use self::assets::{AssetsForeign, AssetsNumber, AssetsSize, AssetsUnused};
+pub use self::characters::Characters;
+pub use self::code_blocks::{CodeBlockLanguage, CodeBlockSyntax};
+pub use self::copyright::Copyright;
+pub use self::footnotes::Footnotes;
 pub use self::headings_space::HeadingsSpace;
+pub use self::html_allow::HtmlAllow;
 pub use self::html_comments::HtmlComments;
+pub use self::images::Images;
+pub use self::interface_id::InterfaceId;
 pub use self::json_schema::JsonSchema;
 pub use self::link_first::LinkFirst;
 pub use self::link_status::LinkStatus;
 pub use self::proposal_ref::ProposalRef;
+pub use self::protocol_collision::ProtocolCollision;
 pub use self::regex::Regex;
 pub use self::relative_links::RelativeLinks;
+pub use self::rfc2119::Rfc2119;
 pub use self::section_order::SectionOrder;
 pub use self::section_required::SectionRequired;
+pub use self::spell::Spell;
+pub use self::table_structure::TableStructure;
+pub use self::whitespace::{
+    FinalNewline, HardTabs, LineEndings, NonBreakingSpaces, TrailingBlankLines, TrailingWhitespace,
+};
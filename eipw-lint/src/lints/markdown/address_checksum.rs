@@ -0,0 +1,142 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet, SourceAnnotation};
+
+use comrak::nodes::{NodeCode, NodeCodeBlock, NodeValue};
+
+use crate::lints::{Context, Error, Lint};
+
+use regex::Regex;
+
+use serde::{Deserialize, Serialize};
+
+use sha3::{Digest, Keccak256};
+
+use std::collections::HashSet;
+use std::fmt::Debug;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AddressChecksum;
+
+/// Mixed-case checksum encoding of an address, as described in EIP-55.
+fn checksum(address: &str) -> String {
+    let hex = address.trim_start_matches("0x").to_ascii_lowercase();
+    let hash = Keccak256::digest(hex.as_bytes());
+
+    let mut result = String::with_capacity(42);
+    result.push_str("0x");
+
+    for (idx, c) in hex.chars().enumerate() {
+        let byte = hash[idx / 2];
+        let nibble = if idx % 2 == 0 { byte >> 4 } else { byte & 0x0f };
+
+        if nibble >= 8 {
+            result.push(c.to_ascii_uppercase());
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+impl Lint for AddressChecksum {
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let re = Regex::new(r"\b0x[0-9a-fA-F]{40}\b").unwrap();
+
+        // Positions (line and byte offset) of addresses that have already been
+        // checked, so repeated addresses on one line are each matched once.
+        let mut seen: HashSet<(usize, usize)> = HashSet::new();
+
+        for node in ctx.body().descendants() {
+            let data = node.data.borrow();
+            let text = match &data.value {
+                NodeValue::Text(text) => text,
+                NodeValue::Code(NodeCode { literal, .. }) => literal,
+                NodeValue::CodeBlock(NodeCodeBlock { literal, .. }) => literal,
+                _ => continue,
+            };
+
+            let first = data.sourcepos.start.line;
+            let last = data.sourcepos.end.line.max(first);
+
+            for matched in re.find_iter(text) {
+                let address = matched.as_str();
+                let expected = checksum(address);
+
+                if address == expected {
+                    continue;
+                }
+
+                let found = (first..=last).find_map(|line| {
+                    let source = ctx.line(line);
+                    source
+                        .match_indices(address)
+                        .map(|(offset, _)| (line, offset))
+                        .find(|position| !seen.contains(position))
+                        .map(|(_, offset)| (line, offset, source))
+                });
+
+                let (line_start, offset, source) = match found {
+                    Some(f) => f,
+                    None => continue,
+                };
+
+                seen.insert((line_start, offset));
+
+                // Addresses in a single case don't carry a checksum at all.
+                let digits = &address[2..];
+                let label = if digits.chars().any(|c| c.is_ascii_lowercase())
+                    && digits.chars().any(|c| c.is_ascii_uppercase())
+                {
+                    "address has an invalid EIP-55 checksum"
+                } else {
+                    "address is not EIP-55 checksummed"
+                };
+
+                let line_offset: usize = ctx
+                    .source()
+                    .split('\n')
+                    .take(line_start - 1)
+                    .map(|l| l.len() + 1)
+                    .sum();
+                let range = line_offset + offset..line_offset + offset + address.len();
+                ctx.fix(range, expected.as_str())?;
+
+                let start = source[..offset].chars().count();
+                let help = format!("use `{}`", expected);
+
+                ctx.report(Snippet {
+                    title: Some(Annotation {
+                        annotation_type: ctx.annotation_type(),
+                        id: Some(slug),
+                        label: Some(label),
+                    }),
+                    slices: vec![Slice {
+                        fold: false,
+                        line_start,
+                        origin: ctx.origin(),
+                        source,
+                        annotations: vec![SourceAnnotation {
+                            annotation_type: ctx.annotation_type(),
+                            label: "",
+                            range: (start, start + address.len()),
+                        }],
+                    }],
+                    footer: vec![Annotation {
+                        annotation_type: AnnotationType::Help,
+                        id: None,
+                        label: Some(&help),
+                    }],
+                    opt: Default::default(),
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,571 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet};
+
+use comrak::nodes::{Ast, AstNode, NodeHtmlBlock, NodeLink};
+
+use crate::lints::{Context, Error, FetchContext, Lint};
+use crate::tree::{self, Next, TraverseExt};
+
+use eipw_preamble::{Field, Preamble};
+
+use scraper::node::Node as HtmlNode;
+use scraper::Html;
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashSet;
+use std::fmt::{Debug, Display};
+use std::path::{Component, Path, PathBuf};
+
+/// Reports references to assets stored in another proposal's directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetsForeign<S> {
+    pub name: S,
+    pub assets: S,
+    pub prefix: S,
+    pub suffix: S,
+}
+
+/// Reports references to asset directories that don't match the proposal's
+/// number, and don't belong to any other proposal either.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetsNumber<S> {
+    pub name: S,
+    pub assets: S,
+    pub prefix: S,
+    pub suffix: S,
+}
+
+/// Checks that every file in the proposal's asset directory is referenced
+/// from the body.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetsUnused<S> {
+    pub name: S,
+    pub assets: S,
+    pub prefix: S,
+}
+
+/// Checks that files in the proposal's asset directory aren't too large.
+///
+/// Files with an extension listed in `exempt` (eg. `md` or `sol`) are
+/// considered text, and are never reported.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetsSize<S> {
+    pub name: S,
+    pub assets: S,
+    pub prefix: S,
+    pub max: u64,
+    pub exempt: Vec<S>,
+}
+
+#[derive(Debug)]
+struct AssetRef {
+    line_start: usize,
+    address: String,
+    directory: String,
+    file: PathBuf,
+}
+
+/// Lexically resolves `.` and `..` components, without touching the
+/// filesystem.
+pub(super) fn normalize(path: &Path) -> PathBuf {
+    let mut output = Vec::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir => match output.last() {
+                Some(Component::Normal(_)) => {
+                    output.pop();
+                }
+                _ => output.push(component),
+            },
+            c => output.push(c),
+        }
+    }
+
+    output.into_iter().collect()
+}
+
+pub(super) fn number<'a>(preamble: &Preamble<'a>, name: &str) -> Option<(Field<'a>, u64)> {
+    let field = preamble.by_name(name)?;
+    let number = field.value().trim().parse().ok()?;
+    Some((field, number))
+}
+
+pub(super) fn own_directory(prefix: &str, number: u64) -> String {
+    format!("{}{}", prefix, number)
+}
+
+fn find_refs<'a>(body: &'a AstNode<'a>, assets: &str) -> Result<Vec<AssetRef>, Error> {
+    let mut visitor = Visitor::default();
+    body.traverse().visit(&mut visitor)?;
+
+    let assets = normalize(Path::new(assets));
+
+    let refs = visitor
+        .links
+        .into_iter()
+        .filter_map(|(line_start, address)| {
+            if address.contains(':') || address.starts_with('/') {
+                return None;
+            }
+
+            let trimmed = address.split(['#', '?']).next().unwrap_or_default();
+            let normalized = normalize(Path::new(trimmed));
+            let relative = normalized.strip_prefix(&assets).ok()?;
+
+            let mut components = relative.components();
+            let directory = match components.next() {
+                Some(Component::Normal(d)) => d.to_string_lossy().into_owned(),
+                _ => return None,
+            };
+
+            Some(AssetRef {
+                line_start,
+                address,
+                directory,
+                file: components.as_path().to_path_buf(),
+            })
+        })
+        .collect();
+
+    Ok(refs)
+}
+
+/// Asset references pointing into a directory named after some other
+/// proposal, along with the path to that proposal.
+fn foreign_refs<'a>(
+    body: &'a AstNode<'a>,
+    own: u64,
+    assets: &str,
+    prefix: &str,
+    suffix: &str,
+) -> Result<Vec<(AssetRef, Option<PathBuf>)>, Error> {
+    let own = own_directory(prefix, own);
+
+    let refs = find_refs(body, assets)?
+        .into_iter()
+        .filter(|r| r.directory != own)
+        .map(|r| {
+            let path = r
+                .directory
+                .strip_prefix(prefix)
+                .and_then(|n| n.parse::<u64>().ok())
+                .map(|n| PathBuf::from(format!("{}{}{}", prefix, n, suffix)));
+            (r, path)
+        })
+        .collect();
+
+    Ok(refs)
+}
+
+fn fetch_foreign(
+    ctx: &FetchContext<'_>,
+    name: &str,
+    assets: &str,
+    prefix: &str,
+    suffix: &str,
+) -> Result<(), Error> {
+    let own = match number(ctx.preamble(), name) {
+        Some((_, n)) => n,
+        None => return Ok(()),
+    };
+
+    foreign_refs(ctx.body(), own, assets, prefix, suffix)?
+        .into_iter()
+        .filter_map(|(_, p)| p)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .for_each(|p| ctx.fetch(p));
+
+    Ok(())
+}
+
+impl<S> Lint for AssetsForeign<S>
+where
+    S: Display + Debug + AsRef<str>,
+{
+    fn find_resources(&self, ctx: &FetchContext<'_>) -> Result<(), Error> {
+        fetch_foreign(
+            ctx,
+            self.name.as_ref(),
+            self.assets.as_ref(),
+            self.prefix.as_ref(),
+            self.suffix.as_ref(),
+        )
+    }
+
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let own = match number(ctx.preamble(), self.name.as_ref()) {
+            Some((_, n)) => n,
+            None => return Ok(()),
+        };
+
+        let refs = foreign_refs(
+            ctx.body(),
+            own,
+            self.assets.as_ref(),
+            self.prefix.as_ref(),
+            self.suffix.as_ref(),
+        )?;
+
+        for (asset, path) in refs {
+            let path = match path {
+                Some(p) => p,
+                None => continue,
+            };
+
+            if ctx.eip(&path).is_err() {
+                continue;
+            }
+
+            let label = format!(
+                "asset `{}` belongs to another proposal (`{}`)",
+                asset.address,
+                path.display(),
+            );
+
+            let footer_label = format!(
+                "copy the file into `{}/{}`",
+                self.assets,
+                own_directory(self.prefix.as_ref(), own),
+            );
+
+            ctx.report(Snippet {
+                title: Some(Annotation {
+                    annotation_type: ctx.annotation_type(),
+                    id: Some(slug),
+                    label: Some(&label),
+                }),
+                slices: vec![Slice {
+                    fold: false,
+                    line_start: asset.line_start,
+                    origin: ctx.origin(),
+                    source: ctx.line(asset.line_start),
+                    annotations: vec![],
+                }],
+                footer: vec![Annotation {
+                    annotation_type: AnnotationType::Help,
+                    id: None,
+                    label: Some(&footer_label),
+                }],
+                opt: Default::default(),
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<S> Lint for AssetsNumber<S>
+where
+    S: Display + Debug + AsRef<str>,
+{
+    fn find_resources(&self, ctx: &FetchContext<'_>) -> Result<(), Error> {
+        fetch_foreign(
+            ctx,
+            self.name.as_ref(),
+            self.assets.as_ref(),
+            self.prefix.as_ref(),
+            self.suffix.as_ref(),
+        )
+    }
+
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let own = match number(ctx.preamble(), self.name.as_ref()) {
+            Some((_, n)) => n,
+            None => return Ok(()),
+        };
+
+        let refs = foreign_refs(
+            ctx.body(),
+            own,
+            self.assets.as_ref(),
+            self.prefix.as_ref(),
+            self.suffix.as_ref(),
+        )?;
+
+        for (asset, path) in refs {
+            if let Some(path) = path {
+                if ctx.eip(&path).is_ok() {
+                    // Belongs to a different proposal, which `AssetsForeign`
+                    // will report.
+                    continue;
+                }
+            }
+
+            let label = format!(
+                "asset directory `{}` does not match the preamble header `{}`",
+                asset.directory, self.name,
+            );
+
+            let footer_label = format!(
+                "assets for this proposal belong in `{}/{}`",
+                self.assets,
+                own_directory(self.prefix.as_ref(), own),
+            );
+
+            ctx.report(Snippet {
+                title: Some(Annotation {
+                    annotation_type: ctx.annotation_type(),
+                    id: Some(slug),
+                    label: Some(&label),
+                }),
+                slices: vec![Slice {
+                    fold: false,
+                    line_start: asset.line_start,
+                    origin: ctx.origin(),
+                    source: ctx.line(asset.line_start),
+                    annotations: vec![],
+                }],
+                footer: vec![Annotation {
+                    annotation_type: AnnotationType::Help,
+                    id: None,
+                    label: Some(&footer_label),
+                }],
+                opt: Default::default(),
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+fn list_own(ctx: &FetchContext<'_>, name: &str, assets: &str, prefix: &str) {
+    if let Some((_, n)) = number(ctx.preamble(), name) {
+        ctx.list(format!("{}/{}", assets, own_directory(prefix, n)).into());
+    }
+}
+
+fn report_field<'a>(
+    ctx: &Context<'a, '_>,
+    slug: &'a str,
+    field: &Field<'a>,
+    label: &str,
+    footer: Vec<Annotation<'_>>,
+) -> Result<(), Error> {
+    ctx.report(Snippet {
+        title: Some(Annotation {
+            annotation_type: ctx.annotation_type(),
+            id: Some(slug),
+            label: Some(label),
+        }),
+        slices: vec![Slice {
+            fold: false,
+            line_start: field.line_start(),
+            origin: ctx.origin(),
+            source: field.source(),
+            annotations: vec![],
+        }],
+        footer,
+        opt: Default::default(),
+    })
+}
+
+impl<S> Lint for AssetsUnused<S>
+where
+    S: Display + Debug + AsRef<str>,
+{
+    fn find_resources(&self, ctx: &FetchContext<'_>) -> Result<(), Error> {
+        list_own(
+            ctx,
+            self.name.as_ref(),
+            self.assets.as_ref(),
+            self.prefix.as_ref(),
+        );
+        Ok(())
+    }
+
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let (field, own) = match number(ctx.preamble(), self.name.as_ref()) {
+            Some(n) => n,
+            None => return Ok(()),
+        };
+
+        let directory = own_directory(self.prefix.as_ref(), own);
+        let path = format!("{}/{}", self.assets, directory);
+
+        let entries = match ctx.existing_listing(Path::new(&path)) {
+            Ok(Some(e)) => e,
+            Ok(None) => return Ok(()),
+            Err(e) => {
+                let label = format!("unable to list directory `{}`: {}", path, e);
+                return report_field(ctx, slug, &field, &label, vec![]);
+            }
+        };
+
+        let referenced: HashSet<_> = find_refs(ctx.body(), self.assets.as_ref())?
+            .into_iter()
+            .filter(|r| r.directory == directory)
+            .map(|r| r.file)
+            .collect();
+
+        let unused: Vec<_> = entries
+            .iter()
+            .filter(|e| !referenced.contains(&e.path))
+            .map(|e| e.path.to_string_lossy().replace('\\', "/"))
+            .collect();
+
+        if unused.is_empty() {
+            return Ok(());
+        }
+
+        let label = format!(
+            "asset(s) in `{}` are never referenced: `{}`",
+            path,
+            unused.join("`, `"),
+        );
+
+        report_field(
+            ctx,
+            slug,
+            &field,
+            &label,
+            vec![Annotation {
+                annotation_type: AnnotationType::Help,
+                id: None,
+                label: Some("link to each asset from the body, or remove it"),
+            }],
+        )
+    }
+}
+
+impl<S> Lint for AssetsSize<S>
+where
+    S: Display + Debug + AsRef<str>,
+{
+    fn find_resources(&self, ctx: &FetchContext<'_>) -> Result<(), Error> {
+        list_own(
+            ctx,
+            self.name.as_ref(),
+            self.assets.as_ref(),
+            self.prefix.as_ref(),
+        );
+        Ok(())
+    }
+
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let (field, own) = match number(ctx.preamble(), self.name.as_ref()) {
+            Some(n) => n,
+            None => return Ok(()),
+        };
+
+        let path = format!(
+            "{}/{}",
+            self.assets,
+            own_directory(self.prefix.as_ref(), own)
+        );
+
+        let entries = match ctx.existing_listing(Path::new(&path)) {
+            Ok(Some(e)) => e,
+            Ok(None) => return Ok(()),
+            Err(e) => {
+                let label = format!("unable to list directory `{}`: {}", path, e);
+                return report_field(ctx, slug, &field, &label, vec![]);
+            }
+        };
+
+        let footer_label = format!("files may be at most {} bytes", self.max);
+
+        for entry in entries {
+            if entry.len <= self.max {
+                continue;
+            }
+
+            let exempt = entry
+                .path
+                .extension()
+                .map(|x| x.to_string_lossy())
+                .map(|x| {
+                    self.exempt
+                        .iter()
+                        .any(|e| x.eq_ignore_ascii_case(e.as_ref()))
+                })
+                .unwrap_or(false);
+
+            if exempt {
+                continue;
+            }
+
+            let label = format!(
+                "asset `{}/{}` is too large ({} bytes)",
+                path,
+                entry.path.to_string_lossy().replace('\\', "/"),
+                entry.len,
+            );
+
+            report_field(
+                ctx,
+                slug,
+                &field,
+                &label,
+                vec![Annotation {
+                    annotation_type: AnnotationType::Help,
+                    id: None,
+                    label: Some(&footer_label),
+                }],
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+struct Visitor {
+    links: Vec<(usize, String)>,
+}
+
+impl Visitor {
+    fn push(&mut self, ast: &Ast, address: &str) {
+        self.links
+            .push((ast.sourcepos.start.line, address.to_owned()));
+    }
+
+    fn html(&mut self, ast: &Ast, html: &str) {
+        let fragment = Html::parse_fragment(html);
+
+        for node in fragment.tree.nodes() {
+            let elem = match node.value() {
+                HtmlNode::Element(e) => e,
+                _ => continue,
+            };
+
+            for (name, value) in elem.attrs() {
+                if name.eq_ignore_ascii_case("src") || name.eq_ignore_ascii_case("href") {
+                    self.push(ast, value);
+                }
+            }
+        }
+    }
+}
+
+impl tree::Visitor for Visitor {
+    type Error = Error;
+
+    fn enter_image(&mut self, ast: &Ast, link: &NodeLink) -> Result<Next, Self::Error> {
+        self.push(ast, &link.url);
+        Ok(Next::TraverseChildren)
+    }
+
+    fn enter_link(&mut self, ast: &Ast, link: &NodeLink) -> Result<Next, Self::Error> {
+        self.push(ast, &link.url);
+        Ok(Next::TraverseChildren)
+    }
+
+    fn enter_html_block(&mut self, ast: &Ast, html: &NodeHtmlBlock) -> Result<Next, Self::Error> {
+        self.html(ast, &html.literal);
+        Ok(Next::TraverseChildren)
+    }
+
+    fn enter_html_inline(&mut self, ast: &Ast, html: &str) -> Result<Next, Self::Error> {
+        self.html(ast, html);
+        Ok(Next::TraverseChildren)
+    }
+}
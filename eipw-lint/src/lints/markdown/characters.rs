@@ -0,0 +1,400 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet, SourceAnnotation};
+
+use crate::lints::{Context, Error, Lint};
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+
+use super::whitespace::{code_lines, lines};
+
+/// Rules for the value of a single preamble field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldCharacters<S> {
+    pub name: S,
+
+    /// Report every non-ASCII character not permitted by `allow`.
+    pub ascii: bool,
+
+    /// Characters permitted in this field, in addition to
+    /// [`Characters::allow`].
+    pub allow: Vec<S>,
+}
+
+/// Reports invisible and bidirectional control characters, and look-alikes
+/// of ASCII letters mixed into otherwise ASCII words, in the preamble and the
+/// body. Look-alikes are not reported in code blocks, where they're usually
+/// string literals, but control characters are. Typographic punctuation (like
+/// curly quotes) is reported in preamble values only, and non-breaking spaces
+/// are left to [`super::NonBreakingSpaces`].
+///
+/// Every character of every entry in `allow` is permitted everywhere, and
+/// `fields` can permit more characters (or fewer) in particular preamble
+/// fields.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Characters<S> {
+    pub allow: Vec<S>,
+    pub fields: Vec<FieldCharacters<S>>,
+}
+
+/// Characters commonly confused with ASCII letters, along with the letter
+/// they resemble. Fullwidth forms are handled separately.
+const CONFUSABLES: &[(char, char)] = &[
+    // Cyrillic
+    ('\u{0430}', 'a'),
+    ('\u{0435}', 'e'),
+    ('\u{043e}', 'o'),
+    ('\u{0440}', 'p'),
+    ('\u{0441}', 'c'),
+    ('\u{0443}', 'y'),
+    ('\u{0445}', 'x'),
+    ('\u{0455}', 's'),
+    ('\u{0456}', 'i'),
+    ('\u{0458}', 'j'),
+    ('\u{0501}', 'd'),
+    ('\u{0410}', 'A'),
+    ('\u{0412}', 'B'),
+    ('\u{0415}', 'E'),
+    ('\u{041a}', 'K'),
+    ('\u{041c}', 'M'),
+    ('\u{041d}', 'H'),
+    ('\u{041e}', 'O'),
+    ('\u{0420}', 'P'),
+    ('\u{0421}', 'C'),
+    ('\u{0422}', 'T'),
+    ('\u{0425}', 'X'),
+    ('\u{0405}', 'S'),
+    ('\u{0406}', 'I'),
+    ('\u{0408}', 'J'),
+    // Greek
+    ('\u{0391}', 'A'),
+    ('\u{0392}', 'B'),
+    ('\u{0395}', 'E'),
+    ('\u{0396}', 'Z'),
+    ('\u{0397}', 'H'),
+    ('\u{0399}', 'I'),
+    ('\u{039a}', 'K'),
+    ('\u{039c}', 'M'),
+    ('\u{039d}', 'N'),
+    ('\u{039f}', 'O'),
+    ('\u{03a1}', 'P'),
+    ('\u{03a4}', 'T'),
+    ('\u{03a5}', 'Y'),
+    ('\u{03a7}', 'X'),
+    ('\u{03bf}', 'o'),
+    // Latin
+    ('\u{0131}', 'i'),
+    ('\u{0261}', 'g'),
+];
+
+/// Typographic punctuation and spacing, with ASCII replacements.
+const TYPOGRAPHIC: &[(char, &str)] = &[
+    ('\u{2002}', " "),
+    ('\u{2003}', " "),
+    ('\u{2009}', " "),
+    ('\u{202f}', " "),
+    ('\u{2010}', "-"),
+    ('\u{2011}', "-"),
+    ('\u{2012}', "-"),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "-"),
+    ('\u{2015}', "-"),
+    ('\u{2018}', "'"),
+    ('\u{2019}', "'"),
+    ('\u{201a}', "'"),
+    ('\u{201b}', "'"),
+    ('\u{2032}', "'"),
+    ('\u{201c}', "\""),
+    ('\u{201d}', "\""),
+    ('\u{201e}', "\""),
+    ('\u{201f}', "\""),
+    ('\u{2033}', "\""),
+    ('\u{2026}', "..."),
+];
+
+fn bidi(c: char) -> bool {
+    matches!(c, '\u{061c}' | '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}')
+}
+
+fn invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00ad}'
+            | '\u{034f}'
+            | '\u{180e}'
+            | '\u{200b}'..='\u{200d}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{206a}'..='\u{206f}'
+            | '\u{feff}'
+    )
+}
+
+fn confusable(c: char) -> Option<char> {
+    if let '\u{ff01}'..='\u{ff5e}' = c {
+        return char::from_u32(c as u32 - 0xfee0);
+    }
+
+    CONFUSABLES
+        .iter()
+        .find(|(confusable, _)| *confusable == c)
+        .map(|(_, ascii)| *ascii)
+}
+
+/// A character to report, as a byte range in its line.
+struct Found {
+    start: usize,
+    end: usize,
+    label: String,
+    replacement: Option<String>,
+}
+
+/// Finds confusables in words that also contain ASCII letters.
+fn mixed_script(text: &str, found: &mut Vec<Found>) {
+    let mut words = vec![];
+    let mut start = None;
+
+    for (idx, c) in text.char_indices() {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(idx),
+            (false, Some(s)) => {
+                words.push((s, idx));
+                start = None;
+            }
+            _ => (),
+        }
+    }
+
+    if let Some(s) = start {
+        words.push((s, text.len()));
+    }
+
+    for (word_start, word_end) in words {
+        let word = &text[word_start..word_end];
+
+        let ascii = word.chars().any(|c| c.is_ascii_alphanumeric());
+        for (idx, c) in word.char_indices() {
+            let replacement = match confusable(c) {
+                Some(r) => r,
+                None => continue,
+            };
+
+            // Fullwidth forms are always out of place.
+            if !ascii && !('\u{ff01}'..='\u{ff5e}').contains(&c) {
+                continue;
+            }
+
+            found.push(Found {
+                start: word_start + idx,
+                end: word_start + idx + c.len_utf8(),
+                label: format!("`{}` (U+{:04X}) looks like `{}`", c, c as u32, replacement),
+                replacement: Some(replacement.to_string()),
+            });
+        }
+    }
+}
+
+/// Replaces invisible and bidirectional control characters in `text` with a
+/// visible escape (like `<U+200B>`), and maps the byte range `start..end` to
+/// a character range in the result.
+fn escape(text: &str, start: usize, end: usize) -> (String, (usize, usize)) {
+    let mut output = String::with_capacity(text.len());
+    let mut range = (0, 0);
+    let mut count = 0;
+
+    for (idx, c) in text.char_indices() {
+        if idx == start {
+            range.0 = count;
+        }
+
+        if idx == end {
+            range.1 = count;
+        }
+
+        if bidi(c) || invisible(c) {
+            let escaped = format!("<U+{:04X}>", c as u32);
+            count += escaped.len();
+            output.push_str(&escaped);
+        } else {
+            count += 1;
+            output.push(c);
+        }
+    }
+
+    if end >= text.len() {
+        range.1 = count;
+    }
+
+    (output, range)
+}
+
+fn permitted<S>(allow: &[S], c: char) -> bool
+where
+    S: AsRef<str>,
+{
+    allow.iter().any(|a| a.as_ref().contains(c))
+}
+
+impl<S> Lint for Characters<S>
+where
+    S: Debug + Display + AsRef<str>,
+{
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let fields: HashMap<_, _> = ctx
+            .preamble()
+            .fields()
+            .map(|f| (f.line_start(), f))
+            .collect();
+
+        let code = code_lines(ctx);
+
+        for line in lines(ctx.source()) {
+            let field = fields.get(&line.number);
+            let rules =
+                field.and_then(|f| self.fields.iter().find(|r| r.name.as_ref() == f.name()));
+
+            // Only check the value of preamble fields.
+            let skip = match field {
+                Some(f) => line.text.len().min(f.name().len() + 1),
+                None => 0,
+            };
+
+            let text = &line.text[skip..];
+            let mut found = vec![];
+
+            for (idx, c) in text.char_indices() {
+                let end = idx + c.len_utf8();
+
+                if bidi(c) {
+                    found.push(Found {
+                        start: idx,
+                        end,
+                        label: format!("bidirectional control character U+{:04X}", c as u32),
+                        replacement: Some(String::new()),
+                    });
+                } else if invisible(c) {
+                    found.push(Found {
+                        start: idx,
+                        end,
+                        label: format!("invisible character U+{:04X}", c as u32),
+                        replacement: Some(String::new()),
+                    });
+                } else if let Some((_, ascii)) = TYPOGRAPHIC.iter().find(|(t, _)| *t == c) {
+                    if let Some(f) = field {
+                        found.push(Found {
+                            start: idx,
+                            end,
+                            label: format!(
+                                "typographic character `{}` (U+{:04X}) in preamble header `{}`",
+                                c,
+                                c as u32,
+                                f.name()
+                            ),
+                            replacement: Some(ascii.to_string()),
+                        });
+                    }
+                }
+            }
+
+            if !code.contains(&line.number) {
+                mixed_script(text, &mut found);
+            }
+
+            if let (Some(f), Some(r)) = (field, rules) {
+                if r.ascii {
+                    for (idx, c) in text.char_indices() {
+                        if c.is_ascii() || found.iter().any(|f| f.start == idx) {
+                            continue;
+                        }
+
+                        found.push(Found {
+                            start: idx,
+                            end: idx + c.len_utf8(),
+                            label: format!(
+                                "non-ASCII character `{}` (U+{:04X}) in preamble header `{}`",
+                                c,
+                                c as u32,
+                                f.name()
+                            ),
+                            replacement: None,
+                        });
+                    }
+                }
+            }
+
+            found.sort_by_key(|f| f.start);
+
+            for mut item in found {
+                let c = match text[item.start..].chars().next() {
+                    Some(c) => c,
+                    None => continue,
+                };
+
+                if permitted(&self.allow, c) || rules.map_or(false, |r| permitted(&r.allow, c)) {
+                    continue;
+                }
+
+                item.start += skip;
+                item.end += skip;
+
+                if let Some(replacement) = &item.replacement {
+                    ctx.fix(
+                        line.offset + item.start..line.offset + item.end,
+                        replacement.as_str(),
+                    )?;
+                }
+
+                report(ctx, slug, line.number, line.text, &item)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn report(ctx: &Context, slug: &str, line: usize, source: &str, item: &Found) -> Result<(), Error> {
+    let help = match item.replacement.as_deref() {
+        Some("") => Some("remove it".to_owned()),
+        Some(r) => Some(format!("use `{}` instead", r)),
+        None => None,
+    };
+
+    let footer = help
+        .as_deref()
+        .map(|h| Annotation {
+            annotation_type: AnnotationType::Help,
+            id: None,
+            label: Some(h),
+        })
+        .into_iter()
+        .collect();
+
+    let (source, range) = escape(source, item.start, item.end);
+
+    ctx.report(Snippet {
+        title: Some(Annotation {
+            annotation_type: ctx.annotation_type(),
+            id: Some(slug),
+            label: Some(&item.label),
+        }),
+        slices: vec![Slice {
+            fold: false,
+            line_start: line,
+            origin: ctx.origin(),
+            source: &source,
+            annotations: vec![SourceAnnotation {
+                annotation_type: ctx.annotation_type(),
+                label: "",
+                range,
+            }],
+        }],
+        footer,
+        opt: Default::default(),
+    })
+}
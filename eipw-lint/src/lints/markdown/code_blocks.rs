@@ -0,0 +1,284 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet, SourceAnnotation};
+
+use comrak::nodes::{Ast, NodeCodeBlock};
+
+use crate::lints::{Context, Error, Lint};
+use crate::tree::{self, Next, TraverseExt};
+
+use regex::Regex;
+
+use serde::{Deserialize, Serialize};
+
+use std::fmt::{Debug, Display};
+
+fn language(node: &NodeCodeBlock) -> Option<&str> {
+    node.info.split_whitespace().next()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeBlockLanguage<S> {
+    /// Languages permitted in the info string of fenced code blocks.
+    pub allowed: Vec<S>,
+}
+
+impl<S> Lint for CodeBlockLanguage<S>
+where
+    S: Debug + Display + AsRef<str>,
+{
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let mut visitor = LanguageVisitor {
+            ctx,
+            slug,
+            allowed: &self.allowed,
+        };
+
+        ctx.body().traverse().visit(&mut visitor)?;
+
+        Ok(())
+    }
+}
+
+struct LanguageVisitor<'a, 'b, 'c, S> {
+    ctx: &'c Context<'a, 'b>,
+    slug: &'c str,
+    allowed: &'c [S],
+}
+
+impl<'a, 'b, 'c, S> tree::Visitor for LanguageVisitor<'a, 'b, 'c, S>
+where
+    S: Display + AsRef<str>,
+{
+    type Error = Error;
+
+    fn enter_code_block(&mut self, ast: &Ast, node: &NodeCodeBlock) -> Result<Next, Self::Error> {
+        if !node.fenced {
+            return Ok(Next::SkipChildren);
+        }
+
+        let label = match language(node) {
+            None => "code block is missing a language".to_owned(),
+            Some(lang) if self.allowed.iter().any(|a| a.as_ref() == lang) => {
+                return Ok(Next::SkipChildren);
+            }
+            Some(lang) => format!("code block language `{}` is not allowed", lang),
+        };
+
+        let allowed = self
+            .allowed
+            .iter()
+            .map(|a| format!("`{}`", a))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let help = format!("use one of: {}", allowed);
+
+        let line_start = ast.sourcepos.start.line;
+
+        self.ctx.report(Snippet {
+            title: Some(Annotation {
+                annotation_type: self.ctx.annotation_type(),
+                id: Some(self.slug),
+                label: Some(&label),
+            }),
+            slices: vec![Slice {
+                fold: false,
+                line_start,
+                origin: self.ctx.origin(),
+                source: self.ctx.line(line_start),
+                annotations: vec![],
+            }],
+            footer: vec![Annotation {
+                annotation_type: AnnotationType::Help,
+                id: None,
+                label: Some(&help),
+            }],
+            opt: Default::default(),
+        })?;
+
+        Ok(Next::SkipChildren)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeBlockSyntax<S> {
+    /// Languages that are checked with a JSON parser (eg. `json`.)
+    pub json: Vec<S>,
+
+    /// Languages that are checked with a TOML parser (eg. `toml`.)
+    pub toml: Vec<S>,
+
+    /// Languages that are checked with a YAML parser (eg. `yaml`.)
+    pub yaml: Vec<S>,
+}
+
+impl<S> Lint for CodeBlockSyntax<S>
+where
+    S: Debug + Display + AsRef<str>,
+{
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let mut visitor = SyntaxVisitor {
+            ctx,
+            slug,
+            lint: self,
+            re: Regex::new(r" at line \d+ column \d+$").unwrap(),
+        };
+
+        ctx.body().traverse().visit(&mut visitor)?;
+
+        Ok(())
+    }
+}
+
+/// A syntax error, with a one-indexed line and column within the block.
+struct SyntaxError {
+    format: &'static str,
+    message: String,
+    line: usize,
+    column: usize,
+}
+
+struct SyntaxVisitor<'a, 'b, 'c, S> {
+    ctx: &'c Context<'a, 'b>,
+    slug: &'c str,
+    lint: &'c CodeBlockSyntax<S>,
+    re: Regex,
+}
+
+impl<'a, 'b, 'c, S> SyntaxVisitor<'a, 'b, 'c, S>
+where
+    S: AsRef<str>,
+{
+    fn strip(&self, message: String) -> String {
+        self.re.replace(&message, "").into_owned()
+    }
+
+    fn check_json(&self, text: &str) -> Option<SyntaxError> {
+        let err = serde_json::from_str::<serde_json::Value>(text).err()?;
+        Some(SyntaxError {
+            format: "JSON",
+            line: err.line(),
+            column: err.column(),
+            message: self.strip(err.to_string()),
+        })
+    }
+
+    fn check_toml(&self, text: &str) -> Option<SyntaxError> {
+        let err = toml::from_str::<toml::Table>(text).err()?;
+
+        let offset = err.span().map(|s| s.start).unwrap_or_default();
+        let before = &text[..offset.min(text.len())];
+        let line = before.matches('\n').count() + 1;
+        let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+
+        Some(SyntaxError {
+            format: "TOML",
+            line,
+            column,
+            message: err.message().trim().replace('\n', "; "),
+        })
+    }
+
+    fn check_yaml(&self, text: &str) -> Option<SyntaxError> {
+        // Each document in a stream (separated by `---`) is checked on its own.
+        let err = serde_yaml::Deserializer::from_str(text)
+            .find_map(|document| serde_yaml::Value::deserialize(document).err())?;
+        let (line, column) = err
+            .location()
+            .map(|l| (l.line(), l.column()))
+            .unwrap_or((1, 1));
+        Some(SyntaxError {
+            format: "YAML",
+            line,
+            column,
+            message: self.strip(err.to_string()),
+        })
+    }
+}
+
+impl<'a, 'b, 'c, S> tree::Visitor for SyntaxVisitor<'a, 'b, 'c, S>
+where
+    S: AsRef<str>,
+{
+    type Error = Error;
+
+    fn enter_code_block(&mut self, ast: &Ast, node: &NodeCodeBlock) -> Result<Next, Self::Error> {
+        let lang = match language(node) {
+            Some(l) if node.fenced => l,
+            _ => return Ok(Next::SkipChildren),
+        };
+
+        let matches = |langs: &[S]| langs.iter().any(|l| l.as_ref() == lang);
+
+        let error = if matches(&self.lint.json) {
+            self.check_json(&node.literal)
+        } else if matches(&self.lint.toml) {
+            self.check_toml(&node.literal)
+        } else if matches(&self.lint.yaml) {
+            self.check_yaml(&node.literal)
+        } else {
+            None
+        };
+
+        let error = match error {
+            Some(e) => e,
+            None => return Ok(Next::SkipChildren),
+        };
+
+        // The first line of content follows the opening fence. Errors past the
+        // end of the input point at the last line of content, and never past
+        // the end of the block (eg. when it's unterminated.)
+        let content_lines = node.literal.lines().count();
+        let line_start = ast.sourcepos.start.line + error.line.clamp(1, content_lines.max(1));
+        let line_start = line_start.min(ast.sourcepos.end.line);
+        let source = self.ctx.line(line_start);
+
+        let indent = ast.sourcepos.start.column.saturating_sub(1);
+        let length = source.chars().count();
+        let column = (indent + error.column.max(1) - 1).min(length.saturating_sub(1));
+
+        // There's nothing to point at on an empty line.
+        let annotations = match length {
+            0 => vec![],
+            _ => vec![SourceAnnotation {
+                annotation_type: self.ctx.annotation_type(),
+                label: &error.message,
+                range: (column, column + 1),
+            }],
+        };
+
+        let footer = match length {
+            0 => vec![Annotation {
+                annotation_type: AnnotationType::Note,
+                id: None,
+                label: Some(&error.message),
+            }],
+            _ => vec![],
+        };
+
+        let label = format!("code block contains invalid {}", error.format);
+
+        self.ctx.report(Snippet {
+            title: Some(Annotation {
+                annotation_type: self.ctx.annotation_type(),
+                id: Some(self.slug),
+                label: Some(&label),
+            }),
+            slices: vec![Slice {
+                fold: false,
+                line_start,
+                origin: self.ctx.origin(),
+                source,
+                annotations,
+            }],
+            footer,
+            opt: Default::default(),
+        })?;
+
+        Ok(Next::SkipChildren)
+    }
+}
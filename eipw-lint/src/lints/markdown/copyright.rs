@@ -0,0 +1,272 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet, SourceAnnotation};
+
+use comrak::nodes::{Ast, AstNode, NodeHeading, NodeLink, NodeValue};
+
+use crate::lints::{Context, Error, Lint};
+
+use regex::Regex;
+
+use serde::{Deserialize, Serialize};
+
+use std::fmt::{Debug, Display};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Copyright<S> {
+    /// Name of the level two section holding the waiver (eg. `Copyright`.)
+    pub section: S,
+
+    /// Expected markdown source of the section. Blank lines and surrounding
+    /// whitespace are ignored, and `{placeholders}` match any text.
+    pub template: S,
+
+    /// Link destination the section must contain (eg. `../LICENSE.md`.)
+    pub license: S,
+}
+
+impl<S> Lint for Copyright<S>
+where
+    S: Debug + Display + AsRef<str>,
+{
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let heading = match find_heading(ctx.body(), self.section.as_ref()) {
+            Some(h) => h,
+            None => return Ok(()),
+        };
+
+        let heading_line = heading.data.borrow().sourcepos.start.line;
+
+        let mut first_line = None;
+        let mut last_line = heading_line;
+        let mut linked = false;
+
+        for sibling in heading.following_siblings().skip(1) {
+            let data = sibling.data.borrow();
+
+            if let NodeValue::Heading(NodeHeading { level, .. }) = data.value {
+                if level <= 2 {
+                    break;
+                }
+            }
+
+            first_line.get_or_insert(data.sourcepos.start.line);
+            last_line = data.sourcepos.end.line;
+
+            linked |= sibling.descendants().any(|d| match &d.data.borrow().value {
+                NodeValue::Link(NodeLink { url, .. }) => url == self.license.as_ref(),
+                _ => false,
+            });
+        }
+
+        let actual: Vec<_> = match first_line {
+            Some(first_line) => (first_line..=last_line)
+                .map(|n| (n, ctx.line(n).trim()))
+                .filter(|(_, l)| !l.is_empty())
+                .collect(),
+            None => vec![],
+        };
+
+        let template: Vec<_> = self
+            .template
+            .as_ref()
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .collect();
+
+        let patterns = template
+            .iter()
+            .map(|l| pattern(l))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::custom)?;
+
+        let diff = diff(&actual, &patterns);
+
+        if !linked {
+            let label = format!(
+                "the `{}` section must link to `{}`",
+                self.section, self.license
+            );
+            ctx.report(Snippet {
+                title: Some(Annotation {
+                    annotation_type: ctx.annotation_type(),
+                    id: Some(slug),
+                    label: Some(&label),
+                }),
+                slices: vec![Slice {
+                    fold: false,
+                    line_start: heading_line,
+                    origin: ctx.origin(),
+                    source: ctx.line(heading_line),
+                    annotations: vec![],
+                }],
+                footer: vec![],
+                opt: Default::default(),
+            })?;
+        }
+
+        if diff.iter().all(|d| matches!(d, Change::Same)) {
+            return Ok(());
+        }
+
+        let source = (heading_line..=last_line)
+            .map(|n| ctx.line(n))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut annotations = vec![];
+        let mut footer_labels = vec![];
+
+        for change in diff {
+            match change {
+                Change::Same => (),
+                Change::Removed(line) => {
+                    let text = ctx.line(line);
+                    let offset: usize = (heading_line..line)
+                        .map(|n| ctx.line(n).chars().count() + 1)
+                        .sum();
+                    let indent = text.chars().take_while(|c| c.is_whitespace()).count();
+                    let start = offset + indent;
+                    annotations.push(SourceAnnotation {
+                        annotation_type: ctx.annotation_type(),
+                        label: "not in the template",
+                        range: (start, start + text.trim().chars().count()),
+                    });
+                    footer_labels.push(format!("- {}", text.trim()));
+                }
+                Change::Added(index) => {
+                    footer_labels.push(format!("+ {}", template[index]));
+                }
+            }
+        }
+
+        let label = format!("the `{}` section does not match the template", self.section);
+
+        let mut footer = vec![Annotation {
+            annotation_type: AnnotationType::Help,
+            id: None,
+            label: Some("differences from the template:"),
+        }];
+        footer.extend(footer_labels.iter().map(|l| Annotation {
+            annotation_type: AnnotationType::Info,
+            id: None,
+            label: Some(l),
+        }));
+
+        ctx.report(Snippet {
+            title: Some(Annotation {
+                annotation_type: ctx.annotation_type(),
+                id: Some(slug),
+                label: Some(&label),
+            }),
+            slices: vec![Slice {
+                fold: false,
+                line_start: heading_line,
+                origin: ctx.origin(),
+                source: &source,
+                annotations,
+            }],
+            footer,
+            opt: Default::default(),
+        })?;
+
+        Ok(())
+    }
+}
+
+fn find_heading<'a>(body: &'a AstNode<'a>, name: &str) -> Option<&'a AstNode<'a>> {
+    body.children().find(|child| {
+        if !matches!(
+            &*child.data.borrow(),
+            Ast {
+                value: NodeValue::Heading(NodeHeading { level: 2, .. }),
+                ..
+            }
+        ) {
+            return false;
+        }
+
+        let text: String = child
+            .descendants()
+            .filter_map(|d| match &d.data.borrow().value {
+                NodeValue::Text(t) => Some(t.clone()),
+                _ => None,
+            })
+            .collect();
+
+        text == name
+    })
+}
+
+/// Build a regular expression matching one line of the template.
+fn pattern(line: &str) -> Result<Regex, regex::Error> {
+    let placeholder = Regex::new(r"\{[^{}]*\}").unwrap();
+
+    let mut re = String::from("^");
+    let mut last = 0;
+
+    for matched in placeholder.find_iter(line) {
+        re.push_str(&regex::escape(&line[last..matched.start()]));
+        re.push_str(".+?");
+        last = matched.end();
+    }
+
+    re.push_str(&regex::escape(&line[last..]));
+    re.push('$');
+
+    Regex::new(&re)
+}
+
+#[derive(Debug)]
+enum Change {
+    Same,
+
+    /// Line number of a source line that isn't in the template.
+    Removed(usize),
+
+    /// Index of a template line that is missing from the source.
+    Added(usize),
+}
+
+/// Longest common subsequence diff between the section's lines and the
+/// template's lines.
+fn diff(actual: &[(usize, &str)], template: &[Regex]) -> Vec<Change> {
+    let rows = actual.len();
+    let cols = template.len();
+
+    let mut table = vec![vec![0usize; cols + 1]; rows + 1];
+
+    for i in (0..rows).rev() {
+        for j in (0..cols).rev() {
+            table[i][j] = if template[j].is_match(actual[i].1) {
+                table[i + 1][j + 1] + 1
+            } else {
+                table[i + 1][j].max(table[i][j + 1])
+            };
+        }
+    }
+
+    let mut changes = vec![];
+    let (mut i, mut j) = (0, 0);
+
+    while i < rows || j < cols {
+        if i < rows && j < cols && template[j].is_match(actual[i].1) {
+            changes.push(Change::Same);
+            i += 1;
+            j += 1;
+        } else if i < rows && (j == cols || table[i + 1][j] >= table[i][j + 1]) {
+            changes.push(Change::Removed(actual[i].0));
+            i += 1;
+        } else {
+            changes.push(Change::Added(j));
+            j += 1;
+        }
+    }
+
+    changes
+}
@@ -0,0 +1,283 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{Annotation, Slice, Snippet, SourceAnnotation};
+
+use comrak::nodes::{AstNode, NodeCode, NodeHeading, NodeValue};
+
+use crate::lints::{Context, Error, Lint};
+
+use regex::Regex;
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::{Debug, Display};
+
+/// Checks that footnote references and definitions match up, and optionally
+/// that definitions are only in the `section` heading (eg. `References`.)
+///
+/// comrak drops unreferenced and duplicate definitions (and turns undefined
+/// references back into text) while parsing, so footnotes are found in the
+/// source instead of the syntax tree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Footnotes<S> {
+    pub section: Option<S>,
+}
+
+#[derive(Debug)]
+struct Footnote {
+    label: String,
+    line: usize,
+    range: (usize, usize),
+}
+
+impl Footnote {
+    fn key(&self) -> String {
+        self.label
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    }
+}
+
+/// Replaces the contents of code spans with spaces, keeping the offsets of
+/// everything else intact.
+fn mask_code(line: &str) -> String {
+    let chars: Vec<_> = line.chars().collect();
+    let mut output = chars.clone();
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        if chars[idx] != '`' {
+            idx += 1;
+            continue;
+        }
+
+        let run = chars[idx..].iter().take_while(|c| **c == '`').count();
+        let start = idx + run;
+
+        let close = (start..chars.len()).find(|&i| {
+            chars[i..].iter().take_while(|c| **c == '`').count() == run
+                && (i == 0 || chars[i - 1] != '`')
+        });
+
+        match close {
+            Some(end) => {
+                for c in &mut output[start..end] {
+                    *c = ' ';
+                }
+                idx = end + run;
+            }
+            None => idx = start,
+        }
+    }
+
+    output.into_iter().collect()
+}
+
+fn heading_text<'a>(node: &'a AstNode<'a>) -> String {
+    let mut text = String::new();
+    for child in node.descendants().skip(1) {
+        match &child.data.borrow().value {
+            NodeValue::Text(t) => text.push_str(t),
+            NodeValue::Code(NodeCode { literal, .. }) => text.push_str(literal),
+            _ => (),
+        }
+    }
+    text
+}
+
+impl<S> Lint for Footnotes<S>
+where
+    S: Debug + Display + AsRef<str>,
+{
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let mut skip = HashSet::new();
+        let mut sections = BTreeMap::new();
+
+        for node in ctx.body().descendants() {
+            let data = node.data.borrow();
+            match data.value {
+                NodeValue::CodeBlock(_) | NodeValue::HtmlBlock(_) => {
+                    skip.extend(data.sourcepos.start.line..=data.sourcepos.end.line);
+                }
+                NodeValue::Heading(NodeHeading { level: 2, .. }) => {
+                    sections.insert(data.sourcepos.start.line, heading_text(node));
+                }
+                _ => (),
+            }
+        }
+
+        let definition_re = Regex::new(r"^ {0,3}\[\^([^\]]+)\]:").unwrap();
+        let reference_re = Regex::new(r"\[\^([^\]\s]+)\]").unwrap();
+
+        let mut definitions: Vec<Footnote> = vec![];
+        let mut references: Vec<Footnote> = vec![];
+
+        let first = ctx.body().data.borrow().sourcepos.start.line;
+        let count = ctx.body_source().split('\n').count();
+
+        for line in first..first + count {
+            if skip.contains(&line) {
+                continue;
+            }
+
+            let source = mask_code(ctx.line(line));
+            let char_range = |start: usize, end: usize| {
+                (
+                    source[..start].chars().count(),
+                    source[..end].chars().count(),
+                )
+            };
+
+            let mut after = 0;
+            if let Some(captures) = definition_re.captures(&source) {
+                let whole = captures.get(0).unwrap();
+                let label = captures.get(1).unwrap();
+                definitions.push(Footnote {
+                    label: label.as_str().to_owned(),
+                    line,
+                    range: char_range(label.start() - 2, label.end() + 1),
+                });
+                after = whole.end();
+            }
+
+            for captures in reference_re.captures_iter(&source[after..]) {
+                let whole = captures.get(0).unwrap();
+                references.push(Footnote {
+                    label: captures[1].to_owned(),
+                    line,
+                    range: char_range(after + whole.start(), after + whole.end()),
+                });
+            }
+        }
+
+        let mut defined: HashMap<String, &Footnote> = HashMap::new();
+
+        for definition in &definitions {
+            let first = match defined.get(&definition.key()) {
+                Some(f) => f,
+                None => {
+                    defined.insert(definition.key(), definition);
+                    continue;
+                }
+            };
+
+            let label = format!(
+                "footnote `[^{}]` is defined more than once",
+                definition.label
+            );
+            self.report(
+                ctx,
+                slug,
+                &label,
+                &[
+                    (first, "first defined here"),
+                    (definition, "redefined here"),
+                ],
+            )?;
+        }
+
+        let mut referenced: HashMap<String, &Footnote> = HashMap::new();
+
+        for reference in &references {
+            if defined.contains_key(&reference.key()) {
+                referenced.entry(reference.key()).or_insert(reference);
+                continue;
+            }
+
+            let label = format!("footnote `[^{}]` is not defined", reference.label);
+            self.report(ctx, slug, &label, &[(reference, "")])?;
+        }
+
+        for definition in &definitions {
+            // Duplicates are already reported above.
+            if !std::ptr::eq(defined[&definition.key()], definition) {
+                continue;
+            }
+
+            let reference = match referenced.get(&definition.key()) {
+                Some(r) => r,
+                None => continue,
+            };
+
+            let section = match &self.section {
+                Some(s) => s.as_ref(),
+                None => continue,
+            };
+
+            let current = sections.range(..=definition.line).next_back();
+            if current.map(|(_, name)| name.trim()) == Some(section) {
+                continue;
+            }
+
+            let label = format!(
+                "footnote `[^{}]` is defined outside the `{}` section",
+                definition.label, section
+            );
+            self.report(
+                ctx,
+                slug,
+                &label,
+                &[(reference, "referenced here"), (definition, "defined here")],
+            )?;
+        }
+
+        for definition in &definitions {
+            if referenced.contains_key(&definition.key()) {
+                continue;
+            }
+
+            // Only report the first definition of duplicates.
+            if !std::ptr::eq(defined[&definition.key()], definition) {
+                continue;
+            }
+
+            let label = format!("footnote `[^{}]` is never referenced", definition.label);
+            self.report(ctx, slug, &label, &[(definition, "")])?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<S> Footnotes<S> {
+    fn report(
+        &self,
+        ctx: &Context,
+        slug: &str,
+        label: &str,
+        spans: &[(&Footnote, &str)],
+    ) -> Result<(), Error> {
+        let slices = spans
+            .iter()
+            .map(|(footnote, annotation)| Slice {
+                fold: false,
+                line_start: footnote.line,
+                origin: ctx.origin(),
+                source: ctx.line(footnote.line),
+                annotations: vec![SourceAnnotation {
+                    annotation_type: ctx.annotation_type(),
+                    label: annotation,
+                    range: footnote.range,
+                }],
+            })
+            .collect();
+
+        ctx.report(Snippet {
+            title: Some(Annotation {
+                annotation_type: ctx.annotation_type(),
+                id: Some(slug),
+                label: Some(label),
+            }),
+            slices,
+            footer: vec![],
+            opt: Default::default(),
+        })
+    }
+}
@@ -0,0 +1,227 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet, SourceAnnotation};
+
+use comrak::nodes::{Ast, NodeHtmlBlock};
+
+use crate::lints::{Context, Error, Lint};
+use crate::tree::{self, Next, TraverseExt};
+
+use scraper::Html;
+
+use serde::{Deserialize, Serialize};
+
+use std::fmt::{Debug, Display};
+
+/// An HTML element permitted by [`HtmlAllow`], and the attributes it may have.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AllowedTag<S> {
+    pub name: S,
+    pub attributes: Vec<S>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HtmlAllow<S> {
+    pub tags: Vec<AllowedTag<S>>,
+}
+
+impl<S> Lint for HtmlAllow<S>
+where
+    S: Debug + Display + AsRef<str>,
+{
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let mut visitor = Visitor {
+            ctx,
+            slug,
+            tags: &self.tags,
+        };
+
+        ctx.body().traverse().visit(&mut visitor)?;
+
+        Ok(())
+    }
+}
+
+/// Markdown syntax that can replace an HTML element, if any.
+fn markdown_equivalent(tag: &str) -> Option<&'static str> {
+    let equivalent = match tag {
+        "a" => "a link like `[text](./eip-1.md)`",
+        "b" | "strong" => "`**text**`",
+        "i" | "em" => "`*text*`",
+        "code" => "backticks, like `` `code` ``",
+        "pre" => "a fenced code block",
+        "img" => "an image like `![alt text](../assets/eip-1/image.png)`",
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "a heading like `## Heading`",
+        "ul" | "ol" | "li" => "a list",
+        "blockquote" => "a block quote, with lines starting with `>`",
+        "table" | "thead" | "tbody" | "tr" | "th" | "td" => "a table",
+        "hr" => "a thematic break (`---`)",
+        "br" => "a backslash (`\\`) at the end of the line",
+        "p" => "a blank line between paragraphs",
+        _ => return None,
+    };
+
+    Some(equivalent)
+}
+
+struct Visitor<'a, 'b, 'c, S> {
+    ctx: &'c Context<'a, 'b>,
+    slug: &'c str,
+    tags: &'c [AllowedTag<S>],
+}
+
+impl<'a, 'b, 'c, S> Visitor<'a, 'b, 'c, S>
+where
+    S: AsRef<str>,
+{
+    fn report(
+        &self,
+        label: &str,
+        line_start: usize,
+        range: Option<(usize, usize)>,
+        help: Option<&str>,
+    ) -> Result<(), Error> {
+        let annotations = range
+            .map(|range| SourceAnnotation {
+                annotation_type: self.ctx.annotation_type(),
+                label: "",
+                range,
+            })
+            .into_iter()
+            .collect();
+
+        let footer = help
+            .map(|h| Annotation {
+                annotation_type: AnnotationType::Help,
+                id: None,
+                label: Some(h),
+            })
+            .into_iter()
+            .collect();
+
+        self.ctx.report(Snippet {
+            title: Some(Annotation {
+                annotation_type: self.ctx.annotation_type(),
+                id: Some(self.slug),
+                label: Some(label),
+            }),
+            slices: vec![Slice {
+                fold: false,
+                line_start,
+                origin: self.ctx.origin(),
+                source: self.ctx.line(line_start),
+                annotations,
+            }],
+            footer,
+            opt: Default::default(),
+        })
+    }
+
+    fn html(&self, ast: &Ast, html: &str) -> Result<Next, Error> {
+        let first = ast.sourcepos.start.line;
+        let last = ast.sourcepos.end.line.max(first);
+
+        // Position (line and byte offset) after the last element found, so
+        // repeated elements are matched to successive occurrences.
+        let mut line = first;
+        let mut column = ast.sourcepos.start.column.saturating_sub(1);
+
+        let fragment = Html::parse_fragment(html);
+
+        // The parser wraps fragments in an `<html>` element.
+        for node in fragment.root_element().descendants().skip(1) {
+            let elem = match node.value().as_element() {
+                Some(e) => e,
+                None => continue,
+            };
+
+            let name = elem.name();
+            let needle = format!("<{}", name);
+
+            // Elements the parser inserted on its own (eg. `<tbody>`) don't
+            // appear in the source, and aren't the author's fault.
+            let found = (line..=last).find_map(|n| {
+                let source = self.ctx.line(n).to_ascii_lowercase();
+                let from = if n == line { column } else { 0 };
+                let rest = source.get(from..)?;
+                let idx = rest.match_indices(&needle).map(|(i, _)| i).find(|i| {
+                    rest[i + needle.len()..]
+                        .chars()
+                        .next()
+                        .map_or(true, |c| c.is_whitespace() || c == '>' || c == '/')
+                })?;
+                Some((n, from + idx, source))
+            });
+
+            let (line_start, start, source) = match found {
+                Some(f) => f,
+                None => continue,
+            };
+
+            line = line_start;
+            column = start + needle.len();
+
+            let tag_range = (
+                source[..start].chars().count(),
+                source[..column].chars().count(),
+            );
+
+            let allowed = self.tags.iter().find(|t| t.name.as_ref() == name);
+
+            let allowed = match allowed {
+                Some(a) => a,
+                None => {
+                    let label = format!("HTML element `<{}>` is not allowed", name);
+                    let help = markdown_equivalent(name).map(|m| format!("use {} instead", m));
+                    self.report(&label, line_start, Some(tag_range), help.as_deref())?;
+                    continue;
+                }
+            };
+
+            let mut attrs: Vec<_> = elem.attrs().map(|(n, _)| n).collect();
+            attrs.sort_unstable();
+
+            for attr in attrs {
+                if allowed.attributes.iter().any(|a| a.as_ref() == attr) {
+                    continue;
+                }
+
+                let range = source[column..]
+                    .find(attr)
+                    .map(|idx| column + idx)
+                    .map(|idx| {
+                        let start = source[..idx].chars().count();
+                        (start, start + attr.chars().count())
+                    });
+
+                let label = format!("HTML attribute `{}` is not allowed on `<{}>`", attr, name);
+                self.report(&label, line_start, range.or(Some(tag_range)), None)?;
+            }
+        }
+
+        Ok(Next::TraverseChildren)
+    }
+}
+
+impl<'a, 'b, 'c, S> tree::Visitor for Visitor<'a, 'b, 'c, S>
+where
+    S: AsRef<str>,
+{
+    type Error = Error;
+
+    fn enter_html_block(
+        &mut self,
+        ast: &Ast,
+        html_block: &NodeHtmlBlock,
+    ) -> Result<Next, Self::Error> {
+        self.html(ast, &html_block.literal)
+    }
+
+    fn enter_html_inline(&mut self, ast: &Ast, html: &str) -> Result<Next, Self::Error> {
+        self.html(ast, html)
+    }
+}
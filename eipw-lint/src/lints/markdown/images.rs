@@ -0,0 +1,247 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet};
+
+use comrak::nodes::{AstNode, NodeCode, NodeValue};
+
+use crate::lints::{Context, Error, FetchContext, Lint};
+
+use scraper::Html;
+
+use serde::{Deserialize, Serialize};
+
+use std::fmt::{Debug, Display};
+use std::path::Path;
+
+use super::assets::{normalize, number, own_directory};
+
+/// Checks that images have alt text, are stored in the proposal's own assets
+/// directory, use one of the permitted `formats` (file extensions), and
+/// optionally that they're at most `max` bytes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Images<S> {
+    pub name: S,
+    pub assets: S,
+    pub prefix: S,
+    pub formats: Vec<S>,
+    pub max: Option<u64>,
+}
+
+#[derive(Debug)]
+struct Image {
+    line_start: usize,
+    src: String,
+    alt: String,
+    html: bool,
+}
+
+fn alt_text<'a>(node: &'a AstNode<'a>) -> String {
+    let mut text = String::new();
+    for child in node.descendants().skip(1) {
+        match &child.data.borrow().value {
+            NodeValue::Text(t) => text.push_str(t),
+            NodeValue::Code(NodeCode { literal, .. }) => text.push_str(literal),
+            _ => (),
+        }
+    }
+    text
+}
+
+fn find_images<'a>(body: &'a AstNode<'a>) -> Vec<Image> {
+    let mut images = vec![];
+
+    for node in body.descendants() {
+        let data = node.data.borrow();
+        let html = match &data.value {
+            NodeValue::Image(link) => {
+                images.push(Image {
+                    line_start: data.sourcepos.start.line,
+                    src: link.url.clone(),
+                    alt: alt_text(node),
+                    html: false,
+                });
+                continue;
+            }
+            NodeValue::HtmlBlock(block) => &block.literal,
+            NodeValue::HtmlInline(html) => html,
+            _ => continue,
+        };
+
+        let fragment = Html::parse_fragment(html);
+        for element in fragment.root_element().descendants() {
+            let element = match element.value().as_element() {
+                Some(e) if e.name() == "img" => e,
+                _ => continue,
+            };
+
+            images.push(Image {
+                line_start: data.sourcepos.start.line,
+                src: element.attr("src").unwrap_or_default().to_owned(),
+                alt: element.attr("alt").unwrap_or_default().to_owned(),
+                html: true,
+            });
+        }
+    }
+
+    images
+}
+
+impl<S> Images<S>
+where
+    S: AsRef<str>,
+{
+    fn directory(&self, own: u64) -> String {
+        format!(
+            "{}/{}",
+            self.assets.as_ref(),
+            own_directory(self.prefix.as_ref(), own)
+        )
+    }
+}
+
+fn report(
+    ctx: &Context,
+    slug: &str,
+    line_start: usize,
+    label: &str,
+    help: &str,
+) -> Result<(), Error> {
+    ctx.report(Snippet {
+        title: Some(Annotation {
+            annotation_type: ctx.annotation_type(),
+            id: Some(slug),
+            label: Some(label),
+        }),
+        slices: vec![Slice {
+            fold: false,
+            line_start,
+            origin: ctx.origin(),
+            source: ctx.line(line_start),
+            annotations: vec![],
+        }],
+        footer: vec![Annotation {
+            annotation_type: AnnotationType::Help,
+            id: None,
+            label: Some(help),
+        }],
+        opt: Default::default(),
+    })
+}
+
+impl<S> Lint for Images<S>
+where
+    S: Display + Debug + AsRef<str>,
+{
+    fn find_resources(&self, ctx: &FetchContext<'_>) -> Result<(), Error> {
+        if self.max.is_none() {
+            return Ok(());
+        }
+
+        if let Some((_, n)) = number(ctx.preamble(), self.name.as_ref()) {
+            ctx.list(self.directory(n).into());
+        }
+
+        Ok(())
+    }
+
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let own = number(ctx.preamble(), self.name.as_ref()).map(|(_, n)| n);
+        let directory = own.map(|n| self.directory(n));
+
+        let entries = match (&directory, self.max) {
+            (Some(d), Some(_)) => ctx.listing(Path::new(d)).ok(),
+            _ => None,
+        };
+
+        let formats = self
+            .formats
+            .iter()
+            .map(|f| format!("`{}`", f))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        for image in find_images(ctx.body()) {
+            if image.alt.trim().is_empty() {
+                let help = match image.html {
+                    true => "add an `alt` attribute describing the image",
+                    false => "describe the image between the brackets, like `![diagram](...)`",
+                };
+                report(
+                    ctx,
+                    slug,
+                    image.line_start,
+                    "image is missing alt text",
+                    help,
+                )?;
+            }
+
+            let trimmed = image.src.split(['#', '?']).next().unwrap_or_default();
+
+            let extension = Path::new(trimmed)
+                .extension()
+                .map(|x| x.to_string_lossy().to_ascii_lowercase());
+
+            let supported = extension.as_ref().map_or(false, |x| {
+                self.formats
+                    .iter()
+                    .any(|f| f.as_ref().eq_ignore_ascii_case(x))
+            });
+
+            if !supported {
+                let label = match &extension {
+                    Some(x) => format!("image format `{}` is not supported", x),
+                    None => format!("image `{}` has no file extension", image.src),
+                };
+                let help = format!("use one of: {}", formats);
+                report(ctx, slug, image.line_start, &label, &help)?;
+            }
+
+            let directory = match &directory {
+                Some(d) => d,
+                None => continue,
+            };
+
+            let relative = if image.src.contains(':') || image.src.starts_with('/') {
+                None
+            } else {
+                normalize(Path::new(trimmed))
+                    .strip_prefix(normalize(Path::new(directory)))
+                    .ok()
+                    .map(Path::to_path_buf)
+            };
+
+            let file = match relative {
+                Some(f) => f,
+                None => {
+                    let label = format!("image `{}` is not stored in `{}/`", image.src, directory);
+                    let help = format!(
+                        "move the image into `{}/` and link to it with a relative path",
+                        directory
+                    );
+                    report(ctx, slug, image.line_start, &label, &help)?;
+                    continue;
+                }
+            };
+
+            let (entries, max) = match (entries, self.max) {
+                (Some(e), Some(m)) => (e, m),
+                _ => continue,
+            };
+
+            let entry = match entries.iter().find(|e| e.path == file) {
+                Some(e) if e.len > max => e,
+                _ => continue,
+            };
+
+            let label = format!("image `{}` is too large ({} bytes)", image.src, entry.len);
+            let help = format!("images may be at most {} bytes", max);
+            report(ctx, slug, image.line_start, &label, &help)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,543 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{Annotation, Slice, Snippet, SourceAnnotation};
+
+use comrak::nodes::{Ast, AstNode, NodeCode, NodeCodeBlock, NodeValue};
+
+use crate::lints::{Context, Error, Lint};
+use crate::tree::{self, Next, TraverseExt};
+
+use regex::Regex;
+
+use serde::{Deserialize, Serialize};
+
+use sha3::{Digest, Keccak256};
+
+use solang_parser::pt::{
+    Comment, ContractPart, ContractTy, Expression, FunctionTy, Loc, SourceUnitPart, Type,
+};
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Debug, Display};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterfaceId<S> {
+    /// Info string of the fenced code blocks to parse (eg. `solidity`.)
+    pub language: S,
+}
+
+impl<S> Lint for InterfaceId<S>
+where
+    S: Debug + Display + AsRef<str>,
+{
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let mut visitor = Collector {
+            language: self.language.as_ref(),
+            blocks: vec![],
+        };
+        ctx.body().traverse().visit(&mut visitor)?;
+        let blocks = visitor.blocks;
+
+        let parsed: Vec<_> = blocks
+            .iter()
+            .filter_map(|(line, literal)| {
+                let (unit, comments) = solang_parser::parse(literal, 0).ok()?;
+                Some(Block {
+                    line: *line,
+                    literal,
+                    interfaces: interfaces(&unit.0),
+                    comments,
+                })
+            })
+            .collect();
+
+        let mut known = Known::default();
+        for block in &parsed {
+            for interface in &block.interfaces {
+                known.add(interface);
+            }
+        }
+
+        let checker = Checker {
+            ctx,
+            slug,
+            known: &known,
+            hex: Regex::new(r"\b0[xX][0-9a-fA-F]{8}\b").unwrap(),
+            keyword: Regex::new(r"(?i)interface|identifier|erc-?165").unwrap(),
+            names: known
+                .interfaces
+                .iter()
+                .map(|(name, id)| {
+                    let re = Regex::new(&format!(r"\b{}\b", regex::escape(name))).unwrap();
+                    (re, name.as_str(), *id)
+                })
+                .collect(),
+        };
+
+        for block in &parsed {
+            checker.check_block(block)?;
+        }
+
+        for node in ctx.body().descendants() {
+            if matches!(
+                node.data.borrow().value,
+                NodeValue::Paragraph | NodeValue::TableCell
+            ) {
+                checker.check_prose(node)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct Collector<'l> {
+    language: &'l str,
+    blocks: Vec<(usize, String)>,
+}
+
+impl<'l> tree::Visitor for Collector<'l> {
+    type Error = Error;
+
+    fn enter_code_block(&mut self, ast: &Ast, node: &NodeCodeBlock) -> Result<Next, Self::Error> {
+        if node.info.split_whitespace().next() == Some(self.language) {
+            self.blocks
+                .push((ast.sourcepos.start.line, node.literal.clone()));
+        }
+        Ok(Next::SkipChildren)
+    }
+}
+
+struct Function {
+    signature: String,
+    selector: u32,
+}
+
+struct Interface {
+    name: String,
+
+    /// Byte offsets of the definition within the code block.
+    start: usize,
+
+    /// `None` when a parameter type couldn't be resolved.
+    functions: Option<Vec<Function>>,
+}
+
+impl Interface {
+    fn id(&self) -> Option<u32> {
+        self.functions
+            .as_ref()
+            .map(|f| f.iter().fold(0, |acc, f| acc ^ f.selector))
+    }
+}
+
+struct Block<'a> {
+    line: usize,
+    literal: &'a str,
+    interfaces: Vec<Interface>,
+    comments: Vec<Comment>,
+}
+
+#[derive(Default)]
+struct Known {
+    interfaces: HashMap<String, u32>,
+    functions: HashMap<String, Vec<(String, u32)>>,
+}
+
+impl Known {
+    fn add(&mut self, interface: &Interface) {
+        let functions = match &interface.functions {
+            Some(f) => f,
+            None => return,
+        };
+
+        if let Some(id) = interface.id() {
+            self.interfaces.insert(interface.name.clone(), id);
+        }
+
+        for function in functions {
+            let name = function.signature.split('(').next().unwrap().to_owned();
+            let entry = self.functions.entry(name).or_default();
+            if !entry.iter().any(|(s, _)| *s == function.signature) {
+                entry.push((function.signature.clone(), function.selector));
+            }
+        }
+    }
+
+    fn contains_value(&self, value: u32) -> bool {
+        self.interfaces.values().any(|v| *v == value)
+            || self
+                .functions
+                .values()
+                .flatten()
+                .any(|(_, selector)| *selector == value)
+    }
+}
+
+fn selector(signature: &str) -> u32 {
+    let hash = Keccak256::digest(signature.as_bytes());
+    u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]])
+}
+
+/// Type definitions (structs, enums, contracts, and user defined value
+/// types) that can appear in function parameters.
+#[derive(Default)]
+struct Types<'a> {
+    structs: HashMap<&'a str, Vec<&'a Expression>>,
+    aliases: HashMap<&'a str, &'a Expression>,
+    addresses: Vec<&'a str>,
+    enums: Vec<&'a str>,
+}
+
+impl<'a> Types<'a> {
+    fn collect_part(&mut self, part: &'a ContractPart) {
+        match part {
+            ContractPart::StructDefinition(s) => {
+                if let Some(name) = &s.name {
+                    let fields = s.fields.iter().map(|f| &f.ty).collect();
+                    self.structs.insert(&name.name, fields);
+                }
+            }
+            ContractPart::EnumDefinition(e) => {
+                if let Some(name) = &e.name {
+                    self.enums.push(&name.name);
+                }
+            }
+            ContractPart::TypeDefinition(t) => {
+                self.aliases.insert(&t.name.name, &t.ty);
+            }
+            _ => (),
+        }
+    }
+
+    fn collect(&mut self, parts: &'a [SourceUnitPart]) {
+        for part in parts {
+            match part {
+                SourceUnitPart::ContractDefinition(c) => {
+                    if let Some(name) = &c.name {
+                        self.addresses.push(&name.name);
+                    }
+
+                    for part in &c.parts {
+                        self.collect_part(part);
+                    }
+                }
+                SourceUnitPart::StructDefinition(s) => {
+                    if let Some(name) = &s.name {
+                        let fields = s.fields.iter().map(|f| &f.ty).collect();
+                        self.structs.insert(&name.name, fields);
+                    }
+                }
+                SourceUnitPart::EnumDefinition(e) => {
+                    if let Some(name) = &e.name {
+                        self.enums.push(&name.name);
+                    }
+                }
+                SourceUnitPart::TypeDefinition(t) => {
+                    self.aliases.insert(&t.name.name, &t.ty);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Canonical ABI type of a parameter, as used in function signatures.
+    fn canonical(&self, expr: &Expression, depth: usize) -> Option<String> {
+        if depth > 32 {
+            // Recursive structs can't be used as parameters.
+            return None;
+        }
+
+        let canonical = match expr {
+            Expression::Type(_, ty) => match ty {
+                Type::Address | Type::AddressPayable => "address".to_owned(),
+                Type::Bool => "bool".to_owned(),
+                Type::String => "string".to_owned(),
+                Type::Int(n) => format!("int{}", n),
+                Type::Uint(n) => format!("uint{}", n),
+                Type::Bytes(n) => format!("bytes{}", n),
+                Type::DynamicBytes => "bytes".to_owned(),
+                Type::Function { .. } => "function".to_owned(),
+                _ => return None,
+            },
+            Expression::ArraySubscript(_, base, size) => {
+                let base = self.canonical(base, depth + 1)?;
+                match size.as_deref() {
+                    None => format!("{}[]", base),
+                    Some(Expression::NumberLiteral(_, n, e, _)) if e.is_empty() => {
+                        format!("{}[{}]", base, n)
+                    }
+                    Some(_) => return None,
+                }
+            }
+            Expression::Variable(ident) => self.named(&ident.name, depth)?,
+            Expression::MemberAccess(_, _, ident) => self.named(&ident.name, depth)?,
+            _ => return None,
+        };
+
+        Some(canonical)
+    }
+
+    fn named(&self, name: &str, depth: usize) -> Option<String> {
+        if let Some(fields) = self.structs.get(name) {
+            let fields = fields
+                .iter()
+                .map(|f| self.canonical(f, depth + 1))
+                .collect::<Option<Vec<_>>>()?;
+            Some(format!("({})", fields.join(",")))
+        } else if let Some(ty) = self.aliases.get(name) {
+            self.canonical(ty, depth + 1)
+        } else if self.enums.contains(&name) {
+            Some("uint8".to_owned())
+        } else if self.addresses.contains(&name) {
+            Some("address".to_owned())
+        } else {
+            None
+        }
+    }
+}
+
+fn interfaces(parts: &[SourceUnitPart]) -> Vec<Interface> {
+    let mut types = Types::default();
+    types.collect(parts);
+
+    let mut result = vec![];
+
+    for part in parts {
+        let contract = match part {
+            SourceUnitPart::ContractDefinition(c) => c,
+            _ => continue,
+        };
+
+        let (name, start) = match (&contract.ty, &contract.name, contract.loc) {
+            (ContractTy::Interface(_), Some(name), Loc::File(_, start, _)) => {
+                (name.name.clone(), start)
+            }
+            _ => continue,
+        };
+
+        let functions = contract
+            .parts
+            .iter()
+            .filter_map(|p| match p {
+                ContractPart::FunctionDefinition(f) if f.ty == FunctionTy::Function => Some(f),
+                _ => None,
+            })
+            .map(|f| {
+                let name = &f.name.as_ref()?.name;
+                let params = f
+                    .params
+                    .iter()
+                    .map(|(_, p)| types.canonical(&p.as_ref()?.ty, 0))
+                    .collect::<Option<Vec<_>>>()?;
+                let signature = format!("{}({})", name, params.join(","));
+                Some(Function {
+                    selector: selector(&signature),
+                    signature,
+                })
+            })
+            .collect::<Option<Vec<_>>>();
+
+        result.push(Interface {
+            name,
+            start,
+            functions,
+        });
+    }
+
+    result
+}
+
+/// Plain text of a node, including inline code.
+fn rendered<'a>(node: &'a AstNode<'a>) -> String {
+    let mut text = String::new();
+
+    for child in node.descendants().skip(1) {
+        match &child.data.borrow().value {
+            NodeValue::Text(t) => text.push_str(t),
+            NodeValue::Code(NodeCode { literal, .. }) => text.push_str(literal),
+            NodeValue::SoftBreak | NodeValue::LineBreak => text.push(' '),
+            _ => (),
+        }
+    }
+
+    text
+}
+
+fn parse_hex(text: &str) -> u32 {
+    u32::from_str_radix(&text[2..], 16).unwrap()
+}
+
+struct Checker<'a, 'b, 'c> {
+    ctx: &'c Context<'a, 'b>,
+    slug: &'c str,
+    known: &'c Known,
+    hex: Regex,
+    keyword: Regex,
+
+    /// Whole-word pattern, name, and ID of each known interface.
+    names: Vec<(Regex, &'c str, u32)>,
+}
+
+impl<'a, 'b, 'c> Checker<'a, 'b, 'c> {
+    fn report(&self, line_start: usize, stated: &str, label: &str) -> Result<(), Error> {
+        let source = self.ctx.line(line_start);
+
+        let annotations = match source.find(stated) {
+            Some(idx) => {
+                let start = source[..idx].chars().count();
+                vec![SourceAnnotation {
+                    annotation_type: self.ctx.annotation_type(),
+                    label: "",
+                    range: (start, start + stated.chars().count()),
+                }]
+            }
+            None => vec![],
+        };
+
+        self.ctx.report(Snippet {
+            title: Some(Annotation {
+                annotation_type: self.ctx.annotation_type(),
+                id: Some(self.slug),
+                label: Some(label),
+            }),
+            slices: vec![Slice {
+                fold: false,
+                line_start,
+                origin: self.ctx.origin(),
+                source,
+                annotations,
+            }],
+            footer: vec![],
+            opt: Default::default(),
+        })
+    }
+
+    /// Check comments (like `/// the ERC-165 identifier for this interface is
+    /// 0x80ac58cd`) preceding each interface definition.
+    fn check_block(&self, block: &Block) -> Result<(), Error> {
+        let mut boundaries: BTreeMap<usize, &Interface> = BTreeMap::new();
+        for interface in &block.interfaces {
+            boundaries.insert(interface.start, interface);
+        }
+
+        for comment in &block.comments {
+            let (loc, text) = match comment {
+                Comment::Line(loc, text)
+                | Comment::Block(loc, text)
+                | Comment::DocLine(loc, text)
+                | Comment::DocBlock(loc, text) => (loc, text),
+            };
+
+            let (start, end) = match loc {
+                Loc::File(_, start, end) => (*start, *end),
+                _ => continue,
+            };
+
+            // The comment applies to the next interface, as long as nothing
+            // but comments and whitespace separates them.
+            let interface = match boundaries.range(end..).next() {
+                Some((offset, interface)) => {
+                    let between = &block.literal[end..*offset];
+                    let only_comments = between
+                        .lines()
+                        .map(str::trim)
+                        .all(|l| l.is_empty() || l.starts_with("//") || l.starts_with('*'));
+                    if !only_comments {
+                        continue;
+                    }
+                    interface
+                }
+                None => continue,
+            };
+
+            let expected = match interface.id() {
+                Some(id) => id,
+                None => continue,
+            };
+
+            if !self.keyword.is_match(text) {
+                continue;
+            }
+
+            for matched in self.hex.find_iter(text) {
+                if parse_hex(matched.as_str()) == expected {
+                    continue;
+                }
+
+                let offset = start + text[..matched.start()].len();
+                let line = block.line + 1 + block.literal[..offset].matches('\n').count();
+
+                let label = format!(
+                    "interface ID of `{}` is `{:#010x}`, not `{}`",
+                    interface.name,
+                    expected,
+                    matched.as_str()
+                );
+                self.report(line, matched.as_str(), &label)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_prose(&self, node: &'a AstNode<'a>) -> Result<(), Error> {
+        let text = rendered(node);
+
+        let data = node.data.borrow();
+        let (first, last) = (data.sourcepos.start.line, data.sourcepos.end.line);
+
+        let mut interfaces = None;
+        let mut functions = None;
+
+        for matched in self.hex.find_iter(&text) {
+            let stated = matched.as_str();
+            let value = parse_hex(stated);
+
+            if self.known.contains_value(value) {
+                continue;
+            }
+
+            // Names mentioned don't depend on the match, so only look once.
+            let interfaces: &Vec<_> = interfaces.get_or_insert_with(|| {
+                self.names
+                    .iter()
+                    .filter(|(re, _, _)| re.is_match(&text))
+                    .map(|(_, name, id)| (*name, *id))
+                    .collect()
+            });
+
+            let functions: &Vec<_> = functions.get_or_insert_with(|| {
+                self.known
+                    .functions
+                    .iter()
+                    .filter(|(name, _)| text.contains(&format!("{}(", name)))
+                    .collect()
+            });
+
+            let label = match (interfaces.as_slice(), functions.as_slice()) {
+                ([(name, id)], _) if self.keyword.is_match(&text) => format!(
+                    "interface ID of `{}` is `{:#010x}`, not `{}`",
+                    name, id, stated
+                ),
+                ([], [(_, overloads)]) if overloads.len() == 1 => format!(
+                    "selector of `{}` is `{:#010x}`, not `{}`",
+                    overloads[0].0, overloads[0].1, stated
+                ),
+                _ => continue,
+            };
+
+            let line = (first..=last.max(first))
+                .find(|n| self.ctx.line(*n).contains(stated))
+                .unwrap_or(first);
+
+            self.report(line, stated, &label)?;
+        }
+
+        Ok(())
+    }
+}
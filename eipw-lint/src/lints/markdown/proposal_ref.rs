@@ -36,6 +36,21 @@ where
     }
 }
 
+/// Numbers of the proposals mentioned in `node`, in the order they appear.
+pub(crate) fn referenced_numbers<'a>(node: &'a AstNode<'a>) -> Result<Vec<u64>, Error> {
+    // With an empty prefix and suffix, each "file name" is just the number.
+    let mut visitor = Visitor::new("", "");
+    node.traverse().visit(&mut visitor)?;
+
+    let numbers = visitor
+        .refs
+        .into_iter()
+        .filter_map(|(_, number, _)| number.to_str()?.parse().ok())
+        .collect();
+
+    Ok(numbers)
+}
+
 impl<S> Lint for ProposalRef<S>
 where
     S: Display + Debug + AsRef<str>,
@@ -0,0 +1,350 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{Annotation, Slice, Snippet, SourceAnnotation};
+
+use comrak::nodes::{AstNode, NodeCode, NodeValue};
+
+use crate::lints::{Context, Error, FetchContext, Lint};
+
+use eipw_preamble::Preamble;
+
+use regex::Regex;
+
+use serde::{Deserialize, Serialize};
+
+use std::fmt::{Debug, Display};
+use std::path::{Path, PathBuf};
+
+/// A kind of scarce protocol number (eg. opcodes) claimed by proposals.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Claim<S> {
+    /// Name of the kind of number, used in messages (eg. `opcode`.)
+    pub kind: S,
+
+    /// Pattern matching the header of table columns that contain claims.
+    pub column: Option<S>,
+
+    /// Pattern matching headings that contain claims.
+    pub heading: Option<S>,
+
+    /// Pattern matching a single number within a table cell or heading.
+    pub value: S,
+}
+
+/// Reports protocol numbers (like opcodes, precompile addresses, and
+/// transaction types) claimed by more than one proposal.
+///
+/// Every proposal in the `proposals` directory (relative to the proposal being
+/// checked) with a `category` of `category`, and a `status` not listed in
+/// `ignore_status`, is compared against.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtocolCollision<S> {
+    pub proposals: S,
+    pub category: S,
+    pub ignore_status: Vec<S>,
+    pub claims: Vec<Claim<S>>,
+}
+
+#[derive(Debug)]
+struct Found {
+    kind: usize,
+    value: String,
+    text: String,
+    line: usize,
+}
+
+struct Patterns {
+    column: Option<Regex>,
+    heading: Option<Regex>,
+    value: Regex,
+}
+
+/// Canonical form of a number, so `0x0A`, `0x0a`, and `10` compare equal.
+fn normalize(text: &str) -> Option<String> {
+    let hex = if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        hex.to_ascii_lowercase()
+    } else {
+        format!("{:x}", text.parse::<u128>().ok()?)
+    };
+
+    let trimmed = hex.trim_start_matches('0');
+    if trimmed.is_empty() {
+        Some("0".into())
+    } else {
+        Some(trimmed.into())
+    }
+}
+
+/// Plain text of a node, including inline code.
+fn rendered<'a>(node: &'a AstNode<'a>) -> String {
+    let mut text = String::new();
+
+    for child in node.descendants().skip(1) {
+        match &child.data.borrow().value {
+            NodeValue::Text(t) => text.push_str(t),
+            NodeValue::Code(NodeCode { literal, .. }) => text.push_str(literal),
+            NodeValue::SoftBreak | NodeValue::LineBreak => text.push(' '),
+            _ => (),
+        }
+    }
+
+    text
+}
+
+impl<S> ProtocolCollision<S>
+where
+    S: AsRef<str>,
+{
+    fn applies(&self, preamble: &Preamble) -> bool {
+        let category = preamble
+            .by_name("category")
+            .map(|f| f.value().trim() == self.category.as_ref())
+            .unwrap_or(false);
+
+        let ignored = preamble
+            .by_name("status")
+            .map(|f| {
+                self.ignore_status
+                    .iter()
+                    .any(|s| s.as_ref() == f.value().trim())
+            })
+            .unwrap_or(false);
+
+        category && !ignored
+    }
+
+    fn patterns(&self) -> Result<Vec<Patterns>, Error> {
+        let compile = |s: &S| Regex::new(s.as_ref()).map_err(Error::custom);
+
+        self.claims
+            .iter()
+            .map(|c| {
+                Ok(Patterns {
+                    column: c.column.as_ref().map(compile).transpose()?,
+                    heading: c.heading.as_ref().map(compile).transpose()?,
+                    value: compile(&c.value)?,
+                })
+            })
+            .collect()
+    }
+}
+
+fn extract<'a>(patterns: &[Patterns], body: &'a AstNode<'a>) -> Vec<Found> {
+    let mut found = vec![];
+
+    let mut push = |kind: usize, text: &str, line: usize| {
+        for matched in patterns[kind].value.find_iter(text) {
+            if let Some(value) = normalize(matched.as_str()) {
+                found.push(Found {
+                    kind,
+                    value,
+                    text: matched.as_str().to_owned(),
+                    line,
+                });
+            }
+        }
+    };
+
+    for node in body.descendants() {
+        let data = node.data.borrow();
+        match data.value {
+            NodeValue::Heading(_) => {
+                let text = rendered(node);
+                for (kind, pattern) in patterns.iter().enumerate() {
+                    if pattern
+                        .heading
+                        .as_ref()
+                        .map_or(false, |h| h.is_match(&text))
+                    {
+                        push(kind, &text, data.sourcepos.start.line);
+                    }
+                }
+            }
+            NodeValue::Table(_) => {
+                let mut rows = node.children();
+                let header: Vec<_> = match rows.next() {
+                    Some(row) => row.children().map(rendered).collect(),
+                    None => continue,
+                };
+
+                for (kind, pattern) in patterns.iter().enumerate() {
+                    let column = match &pattern.column {
+                        Some(c) => c,
+                        None => continue,
+                    };
+
+                    let index = match header.iter().position(|h| column.is_match(h)) {
+                        Some(i) => i,
+                        None => continue,
+                    };
+
+                    for row in node.children().skip(1) {
+                        if let Some(cell) = row.children().nth(index) {
+                            let line = row.data.borrow().sourcepos.start.line;
+                            push(kind, &rendered(cell), line);
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    found
+}
+
+fn annotation<'a>(ctx: &Context, source: &str, text: &str, label: &'a str) -> SourceAnnotation<'a> {
+    let range = match source.find(text) {
+        Some(idx) => {
+            let start = source[..idx].chars().count();
+            (start, start + text.chars().count())
+        }
+        None => (0, 0),
+    };
+
+    SourceAnnotation {
+        annotation_type: ctx.annotation_type(),
+        label,
+        range,
+    }
+}
+
+impl<S> Lint for ProtocolCollision<S>
+where
+    S: Debug + Display + AsRef<str>,
+{
+    fn find_resources(&self, ctx: &FetchContext<'_>) -> Result<(), Error> {
+        if self.applies(ctx.preamble()) {
+            ctx.fetch_directory(self.proposals.as_ref().into());
+        }
+        Ok(())
+    }
+
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        if !self.applies(ctx.preamble()) {
+            return Ok(());
+        }
+
+        let patterns = self.patterns()?;
+        let own = extract(&patterns, ctx.body());
+        if own.is_empty() {
+            return Ok(());
+        }
+
+        let directory = Path::new(self.proposals.as_ref());
+        let mut entries: Vec<_> = match ctx.existing_listing(directory) {
+            Ok(Some(e)) => e.iter().collect(),
+            Ok(None) => return Ok(()),
+            Err(e) => {
+                let label = format!("unable to list directory `{}`: {}", directory.display(), e);
+                return ctx.report(Snippet {
+                    title: Some(Annotation {
+                        annotation_type: ctx.annotation_type(),
+                        id: Some(slug),
+                        label: Some(&label),
+                    }),
+                    slices: vec![Slice {
+                        fold: false,
+                        line_start: own[0].line,
+                        origin: ctx.origin(),
+                        source: ctx.line(own[0].line),
+                        annotations: vec![],
+                    }],
+                    footer: vec![],
+                    opt: Default::default(),
+                });
+            }
+        };
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let own_name = ctx.origin().and_then(|o| Path::new(o).file_name());
+
+        for entry in entries {
+            if entry.path.components().count() != 1
+                || entry.path.extension().map_or(true, |x| x != "md")
+                || Some(entry.path.as_os_str()) == own_name
+            {
+                continue;
+            }
+
+            let path = directory.join(&entry.path);
+            let other = match ctx.eip(&path) {
+                Ok(o) => o,
+                Err(_) => continue,
+            };
+
+            if !self.applies(other.preamble()) {
+                continue;
+            }
+
+            let other_origin = match ctx.origin() {
+                Some(o) => Path::new(o)
+                    .parent()
+                    .unwrap_or_else(|| Path::new("."))
+                    .join(&path),
+                None => path.clone(),
+            };
+            let other_origin: PathBuf = other_origin.components().collect();
+            let other_origin = other_origin.to_string_lossy();
+
+            for theirs in extract(&patterns, other.body()) {
+                for mine in own
+                    .iter()
+                    .filter(|m| m.kind == theirs.kind && m.value == theirs.value)
+                {
+                    let label = format!(
+                        "{} `{}` is also claimed by `{}`",
+                        self.claims[mine.kind].kind,
+                        mine.text,
+                        entry.path.display(),
+                    );
+
+                    let source = ctx.line(mine.line);
+                    let other_source = other.line(theirs.line);
+
+                    ctx.report(Snippet {
+                        title: Some(Annotation {
+                            annotation_type: ctx.annotation_type(),
+                            id: Some(slug),
+                            label: Some(&label),
+                        }),
+                        slices: vec![
+                            Slice {
+                                fold: false,
+                                line_start: mine.line,
+                                origin: ctx.origin(),
+                                source,
+                                annotations: vec![annotation(
+                                    ctx,
+                                    source,
+                                    &mine.text,
+                                    "claimed here",
+                                )],
+                            },
+                            Slice {
+                                fold: false,
+                                line_start: theirs.line,
+                                origin: Some(&other_origin),
+                                source: other_source,
+                                annotations: vec![annotation(
+                                    ctx,
+                                    other_source,
+                                    &theirs.text,
+                                    "also claimed here",
+                                )],
+                            },
+                        ],
+                        footer: vec![],
+                        opt: Default::default(),
+                    })?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -4,9 +4,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet};
+use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet, SourceAnnotation};
 
-use comrak::nodes::{Ast, NodeCode, NodeCodeBlock, NodeHtmlBlock, NodeLink};
+use comrak::nodes::{
+    Ast, AstNode, NodeCode, NodeCodeBlock, NodeHeading, NodeHtmlBlock, NodeLink, NodeValue,
+};
 
 use crate::lints::{Context, Error, Lint};
 use crate::tree::{self, Next, TraverseExt};
@@ -15,6 +17,7 @@ use ::regex::Regex as TextRegex;
 
 use serde::{Deserialize, Serialize};
 
+use std::collections::BTreeMap;
 use std::fmt::{Debug, Display};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
@@ -23,15 +26,25 @@ use std::fmt::{Debug, Display};
 pub enum Mode {
     /// Ensure that each syntax node individually doesn't contain the pattern.
     Excludes,
-    // TODO: Add includes/excludes modes that first renders to plain text, then
-    //       matches the pattern.
+
+    /// Ensure that the rendered plain text matches the pattern at least once.
+    Includes,
+
+    /// Ensure that the rendered plain text of each block (paragraph, heading,
+    /// or table cell) doesn't contain the pattern, even when a match spans
+    /// multiple inline nodes.
+    ExcludesRendered,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Regex<S> {
     pub mode: Mode,
     pub pattern: S,
     pub message: S,
+
+    /// When set, only check the level two sections with these names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sections: Option<Vec<S>>,
 }
 
 impl<S> Lint for Regex<S>
@@ -42,23 +55,163 @@ where
         let pattern = self.pattern.as_ref();
         let re = TextRegex::new(pattern).map_err(Error::custom)?;
 
-        let mut visitor = match self.mode {
-            Mode::Excludes => ExcludesVisitor {
-                ctx,
-                re,
-                message: self.message.as_ref(),
-                pattern,
-                slug,
-            },
+        let sections = Sections::new(ctx, self.sections.as_deref());
+
+        let checker = Checker {
+            ctx,
+            re,
+            message: self.message.as_ref(),
+            pattern,
+            slug,
         };
 
-        ctx.body().traverse().visit(&mut visitor)?;
+        match self.mode {
+            Mode::Excludes => {
+                let mut visitor = ExcludesVisitor {
+                    checker,
+                    sections,
+                    in_scope: true,
+                };
+                visitor.in_scope = visitor.sections.contains(None);
+                ctx.body().traverse().visit(&mut visitor)?;
+            }
+            Mode::Includes => checker.includes(&sections)?,
+            Mode::ExcludesRendered => checker.excludes_rendered(&sections)?,
+        }
 
         Ok(())
     }
 }
 
-struct ExcludesVisitor<'a, 'b, 'c> {
+/// Level two headings, by line number, and the names of the sections to check.
+struct Sections<'s> {
+    headings: BTreeMap<usize, String>,
+    filter: Option<Vec<&'s str>>,
+}
+
+impl<'s> Sections<'s> {
+    fn new<S>(ctx: &Context, filter: Option<&'s [S]>) -> Self
+    where
+        S: AsRef<str>,
+    {
+        let headings = ctx
+            .body()
+            .descendants()
+            .filter_map(|node| match &*node.data.borrow() {
+                Ast {
+                    value: NodeValue::Heading(NodeHeading { level: 2, .. }),
+                    sourcepos,
+                    ..
+                } => Some((sourcepos.start.line, rendered(ctx, node).text)),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            headings,
+            filter: filter.map(|f| f.iter().map(AsRef::as_ref).collect()),
+        }
+    }
+
+    /// Name of the section containing `line`, if any.
+    fn at(&self, line: usize) -> Option<&str> {
+        self.headings
+            .range(..=line)
+            .next_back()
+            .map(|(_, name)| name.as_str())
+    }
+
+    fn contains(&self, section: Option<&str>) -> bool {
+        match (&self.filter, section) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(filter), Some(section)) => filter.contains(&section),
+        }
+    }
+
+    fn contains_line(&self, line: usize) -> bool {
+        self.contains(self.at(line))
+    }
+}
+
+/// Plain text of a block, along with the source line and column (both
+/// one-indexed, the column counted in characters) of each character.
+struct Rendered {
+    line: usize,
+    text: String,
+    positions: Vec<Option<(usize, usize)>>,
+}
+
+fn rendered<'a>(ctx: &Context, node: &'a AstNode<'a>) -> Rendered {
+    let mut result = Rendered {
+        line: node.data.borrow().sourcepos.start.line,
+        text: String::new(),
+        positions: Vec::new(),
+    };
+
+    for child in node.descendants().skip(1) {
+        let data = child.data.borrow();
+        match &data.value {
+            NodeValue::Text(txt) => {
+                let count = txt.chars().count();
+                result.text.push_str(txt);
+
+                // Text nodes don't always match the source exactly (escapes,
+                // entities), so search the line for the text.
+                let line = data.sourcepos.start.line;
+                let found = match line {
+                    0 => None,
+                    _ => {
+                        let source = ctx.line(line);
+                        let from = source
+                            .get(..data.sourcepos.start.column.saturating_sub(1))
+                            .map(|s| s.chars().count())
+                            .unwrap_or_default();
+                        locate(source, from, txt)
+                    }
+                };
+
+                match found {
+                    Some((start, _)) => result
+                        .positions
+                        .extend((0..count).map(|idx| Some((line, start + idx + 1)))),
+                    None => result.positions.extend((0..count).map(|_| None)),
+                }
+            }
+            NodeValue::SoftBreak | NodeValue::LineBreak => {
+                result.text.push(' ');
+                result.positions.push(None);
+            }
+            _ => (),
+        }
+    }
+
+    result
+}
+
+/// Find `needle` in `haystack`, preferring matches at or after the character
+/// offset `from`. Returns a character range.
+fn locate(haystack: &str, from: usize, needle: &str) -> Option<(usize, usize)> {
+    if needle.is_empty() {
+        return None;
+    }
+
+    let from_byte = haystack
+        .char_indices()
+        .nth(from)
+        .map(|(idx, _)| idx)
+        .unwrap_or(haystack.len());
+
+    let byte = haystack[from_byte..]
+        .find(needle)
+        .map(|idx| idx + from_byte)
+        .or_else(|| haystack.find(needle))?;
+
+    let start = haystack[..byte].chars().count();
+    Some((start, start + needle.chars().count()))
+}
+
+struct Checker<'a, 'b, 'c> {
     ctx: &'c Context<'a, 'b>,
     re: TextRegex,
     pattern: &'c str,
@@ -66,17 +219,15 @@ struct ExcludesVisitor<'a, 'b, 'c> {
     message: &'c str,
 }
 
-impl<'a, 'b, 'c> ExcludesVisitor<'a, 'b, 'c> {
-    fn check(&self, ast: &Ast, buf: &str) -> Result<Next, Error> {
-        if !self.re.is_match(buf) {
-            return Ok(Next::TraverseChildren);
-        }
-
+impl<'a, 'b, 'c> Checker<'a, 'b, 'c> {
+    fn report(
+        &self,
+        line_start: usize,
+        source: &str,
+        annotations: Vec<SourceAnnotation>,
+    ) -> Result<(), Error> {
         let footer_label = format!("the pattern in question: `{}`", self.pattern);
 
-        // TODO: Actually annotate the matches for `Mode::Excludes`.
-
-        let source = self.ctx.source_for_text(ast.sourcepos.start.line, buf);
         self.ctx.report(Snippet {
             title: Some(Annotation {
                 annotation_type: self.ctx.annotation_type(),
@@ -85,10 +236,10 @@ impl<'a, 'b, 'c> ExcludesVisitor<'a, 'b, 'c> {
             }),
             slices: vec![Slice {
                 fold: false,
-                line_start: ast.sourcepos.start.line,
+                line_start,
                 origin: self.ctx.origin(),
-                source: &source,
-                annotations: vec![],
+                source,
+                annotations,
             }],
             footer: vec![Annotation {
                 id: None,
@@ -96,19 +247,154 @@ impl<'a, 'b, 'c> ExcludesVisitor<'a, 'b, 'c> {
                 label: Some(&footer_label),
             }],
             opt: Default::default(),
-        })?;
+        })
+    }
+
+    fn check_node(&self, ast: &Ast, buf: &str) -> Result<Next, Error> {
+        if !self.re.is_match(buf) {
+            return Ok(Next::TraverseChildren);
+        }
+
+        let source = self.ctx.source_for_text(ast.sourcepos.start.line, buf);
+        let mut cursor = ast.sourcepos.start.column.saturating_sub(1);
+        let mut annotations = vec![];
+
+        for matched in self.re.find_iter(buf) {
+            if let Some(range) = locate(&source, cursor, matched.as_str()) {
+                cursor = range.1;
+                annotations.push(SourceAnnotation {
+                    annotation_type: self.ctx.annotation_type(),
+                    label: "",
+                    range,
+                });
+            }
+        }
+
+        self.report(ast.sourcepos.start.line, &source, annotations)?;
 
         Ok(Next::TraverseChildren)
     }
+
+    fn blocks(&self, sections: &Sections) -> Vec<Rendered> {
+        self.ctx
+            .body()
+            .descendants()
+            .filter(|node| {
+                matches!(
+                    node.data.borrow().value,
+                    NodeValue::Paragraph | NodeValue::Heading(_) | NodeValue::TableCell
+                )
+            })
+            .map(|node| rendered(self.ctx, node))
+            .filter(|block| sections.contains_line(block.line))
+            .collect()
+    }
+
+    fn includes(&self, sections: &Sections) -> Result<(), Error> {
+        let blocks = self.blocks(sections);
+
+        if blocks.iter().any(|b| self.re.is_match(&b.text)) {
+            return Ok(());
+        }
+
+        let line_start = match &sections.filter {
+            None => blocks.first().map(|b| b.line),
+            Some(_) => sections
+                .headings
+                .iter()
+                .find(|(_, name)| sections.contains(Some(name)))
+                .map(|(line, _)| *line),
+        };
+
+        match line_start {
+            Some(line_start) => self.report(line_start, self.ctx.line(line_start), vec![]),
+
+            // There's nothing to check (eg. none of the sections exist.)
+            None => Ok(()),
+        }
+    }
+
+    fn excludes_rendered(&self, sections: &Sections) -> Result<(), Error> {
+        for block in self.blocks(sections) {
+            let chars: Vec<_> = block.text.char_indices().map(|(idx, _)| idx).collect();
+
+            for matched in self.re.find_iter(&block.text) {
+                let first = chars.partition_point(|idx| *idx < matched.start());
+                let last = chars.partition_point(|idx| *idx < matched.end());
+
+                // Group the matched characters by source line.
+                let mut lines: BTreeMap<usize, (usize, usize)> = BTreeMap::new();
+                for (line, column) in block.positions[first..last].iter().flatten() {
+                    let entry = lines.entry(*line).or_insert((*column, *column));
+                    entry.0 = entry.0.min(*column);
+                    entry.1 = entry.1.max(*column);
+                }
+
+                let (line_start, line_end) = match (lines.keys().next(), lines.keys().last()) {
+                    (Some(s), Some(e)) => (*s, *e),
+                    _ => {
+                        let line = block.line;
+                        self.report(line, self.ctx.line(line), vec![])?;
+                        continue;
+                    }
+                };
+
+                let source_lines: Vec<_> =
+                    (line_start..=line_end).map(|n| self.ctx.line(n)).collect();
+
+                let mut annotations = vec![];
+                let mut offset = 0;
+                for (n, text) in (line_start..=line_end).zip(&source_lines) {
+                    if let Some((start, end)) = lines.get(&n) {
+                        annotations.push(SourceAnnotation {
+                            annotation_type: self.ctx.annotation_type(),
+                            label: "",
+                            range: (offset + start - 1, offset + end),
+                        });
+                    }
+                    offset += text.chars().count() + 1;
+                }
+
+                self.report(line_start, &source_lines.join("\n"), annotations)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct ExcludesVisitor<'a, 'b, 'c, 's> {
+    checker: Checker<'a, 'b, 'c>,
+    sections: Sections<'s>,
+    in_scope: bool,
 }
 
-impl<'a, 'b, 'c> tree::Visitor for ExcludesVisitor<'a, 'b, 'c> {
+impl<'a, 'b, 'c, 's> ExcludesVisitor<'a, 'b, 'c, 's> {
+    fn check(&self, ast: &Ast, buf: &str) -> Result<Next, Error> {
+        if self.in_scope {
+            self.checker.check_node(ast, buf)
+        } else {
+            Ok(Next::SkipChildren)
+        }
+    }
+}
+
+impl<'a, 'b, 'c, 's> tree::Visitor for ExcludesVisitor<'a, 'b, 'c, 's> {
     type Error = Error;
 
     fn enter_front_matter(&mut self, _: &Ast, _: &str) -> Result<Next, Self::Error> {
         Ok(Next::SkipChildren)
     }
 
+    fn enter_heading(&mut self, ast: &Ast, heading: &NodeHeading) -> Result<Next, Self::Error> {
+        if heading.level == 2 {
+            let line = ast.sourcepos.start.line;
+            self.in_scope = self.sections.contains_line(line);
+        }
+
+        Ok(Next::TraverseChildren)
+    }
+
     fn enter_code(&mut self, _ast: &Ast, _code: &NodeCode) -> Result<Next, Self::Error> {
         Ok(Next::SkipChildren)
     }
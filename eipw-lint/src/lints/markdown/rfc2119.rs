@@ -0,0 +1,277 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet, SourceAnnotation};
+
+use comrak::nodes::{Ast, AstNode, NodeCode, NodeCodeBlock, NodeHeading, NodeHtmlBlock, NodeValue};
+
+use crate::lints::{Context, Error, Lint};
+use crate::tree::{self, Next, TraverseExt};
+
+use regex::Regex;
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Display};
+
+/// Keywords that are only reported as mis-cased when they aren't written in
+/// all capitals. The adjectives (`REQUIRED`, `RECOMMENDED`, and `OPTIONAL`)
+/// are too common in ordinary prose to be flagged in lower case.
+const VERBS: &[&str] = &["MUST", "SHALL", "SHOULD", "MAY"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Rfc2119<S> {
+    /// The paragraph that must appear in any proposal using the keywords.
+    pub boilerplate: S,
+
+    /// Level two sections where keywords must be written in all capitals.
+    pub normative: Vec<S>,
+
+    /// Level two sections where keywords should not appear at all.
+    pub non_normative: Vec<S>,
+}
+
+impl<S> Lint for Rfc2119<S>
+where
+    S: Debug + Display + AsRef<str>,
+{
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let boilerplate = normalize(self.boilerplate.as_ref());
+
+        let mut headings = HashMap::new();
+        let mut boilerplates = HashSet::new();
+
+        for node in ctx.body().descendants() {
+            let data = node.data.borrow();
+            match &data.value {
+                NodeValue::Heading(NodeHeading { level: 2, .. }) => {
+                    headings.insert(data.sourcepos.start.line, rendered(node));
+                }
+                NodeValue::Paragraph if normalize(&rendered(node)).contains(&boilerplate) => {
+                    boilerplates.insert(data.sourcepos.start.line);
+                }
+                _ => (),
+            }
+        }
+
+        let mut visitor = Visitor {
+            ctx,
+            slug,
+            lint: self,
+            re: Regex::new(
+                r"(?i)\b(?:MUST\s+NOT|MUST|REQUIRED|SHALL\s+NOT|SHALL|SHOULD\s+NOT|SHOULD|NOT\s+RECOMMENDED|RECOMMENDED|MAY|OPTIONAL)\b",
+            )
+            .unwrap(),
+            headings,
+            boilerplates,
+            section: None,
+            first_use: None,
+        };
+
+        ctx.body().traverse().visit(&mut visitor)?;
+
+        if visitor.boilerplates.is_empty() {
+            if let Some(line_start) = visitor.first_use {
+                let help = format!("add this paragraph: {}", boilerplate);
+                ctx.report(Snippet {
+                    title: Some(Annotation {
+                        annotation_type: ctx.annotation_type(),
+                        id: Some(slug),
+                        label: Some("normative keywords are used without the RFC 2119 boilerplate"),
+                    }),
+                    slices: vec![Slice {
+                        fold: false,
+                        line_start,
+                        origin: ctx.origin(),
+                        source: ctx.line(line_start),
+                        annotations: vec![],
+                    }],
+                    footer: vec![Annotation {
+                        annotation_type: AnnotationType::Help,
+                        id: None,
+                        label: Some(&help),
+                    }],
+                    opt: Default::default(),
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn rendered<'a>(node: &'a AstNode<'a>) -> String {
+    let mut text = String::new();
+
+    for child in node.descendants().skip(1) {
+        match &child.data.borrow().value {
+            NodeValue::Text(t) => text.push_str(t),
+            NodeValue::Code(NodeCode { literal, .. }) => text.push_str(literal),
+            NodeValue::SoftBreak | NodeValue::LineBreak => text.push(' '),
+            _ => (),
+        }
+    }
+
+    text
+}
+
+struct Visitor<'a, 'b, 'c, S> {
+    ctx: &'c Context<'a, 'b>,
+    slug: &'c str,
+    lint: &'c Rfc2119<S>,
+    re: Regex,
+    headings: HashMap<usize, String>,
+    boilerplates: HashSet<usize>,
+    section: Option<String>,
+    first_use: Option<usize>,
+}
+
+impl<'a, 'b, 'c, S> Visitor<'a, 'b, 'c, S>
+where
+    S: AsRef<str>,
+{
+    fn is_section(sections: &[S], section: &str) -> bool {
+        sections.iter().any(|s| s.as_ref() == section)
+    }
+
+    fn check(&mut self, ast: &Ast, text: &str) -> Result<(), Error> {
+        let line_start = ast.sourcepos.start.line;
+        let source = self.ctx.line(line_start);
+        let section = self.section.as_deref().unwrap_or_default();
+
+        let normative = Self::is_section(&self.lint.normative, section);
+        let non_normative = Self::is_section(&self.lint.non_normative, section);
+
+        let mut cursor = 0;
+
+        for matched in self.re.find_iter(text) {
+            let keyword = matched.as_str();
+            let upper = keyword.to_uppercase();
+            let capitals = keyword == upper;
+
+            if capitals {
+                self.first_use.get_or_insert(line_start);
+            }
+
+            let label;
+            let footer;
+
+            if capitals && non_normative {
+                label = format!(
+                    "normative keyword `{}` used in non-normative section `{}`",
+                    keyword, section
+                );
+                footer =
+                    "rephrase without the keyword, or move the requirement into the specification"
+                        .to_owned();
+            } else if !capitals && normative && VERBS.iter().any(|v| upper.starts_with(v)) {
+                label = format!("normative keyword `{}` is not in all capitals", keyword);
+                footer = format!(
+                    "use `{}` if this is a requirement, otherwise rephrase",
+                    normalize(&upper)
+                );
+            } else {
+                continue;
+            }
+
+            // Text nodes don't always match the source exactly, so search the
+            // line for the keyword.
+            let position = source
+                .get(cursor..)
+                .and_then(|rest| rest.find(keyword))
+                .map(|idx| idx + cursor);
+
+            let annotations = match position {
+                Some(position) => {
+                    cursor = position + keyword.len();
+                    let start = source[..position].chars().count();
+                    vec![SourceAnnotation {
+                        annotation_type: self.ctx.annotation_type(),
+                        label: "",
+                        range: (start, start + keyword.chars().count()),
+                    }]
+                }
+                None => vec![],
+            };
+
+            self.ctx.report(Snippet {
+                title: Some(Annotation {
+                    annotation_type: self.ctx.annotation_type(),
+                    id: Some(self.slug),
+                    label: Some(&label),
+                }),
+                slices: vec![Slice {
+                    fold: false,
+                    line_start,
+                    origin: self.ctx.origin(),
+                    source,
+                    annotations,
+                }],
+                footer: vec![Annotation {
+                    annotation_type: AnnotationType::Help,
+                    id: None,
+                    label: Some(&footer),
+                }],
+                opt: Default::default(),
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, 'b, 'c, S> tree::Visitor for Visitor<'a, 'b, 'c, S>
+where
+    S: AsRef<str>,
+{
+    type Error = Error;
+
+    fn enter_front_matter(&mut self, _: &Ast, _: &str) -> Result<Next, Self::Error> {
+        Ok(Next::SkipChildren)
+    }
+
+    fn enter_code(&mut self, _: &Ast, _: &NodeCode) -> Result<Next, Self::Error> {
+        Ok(Next::SkipChildren)
+    }
+
+    fn enter_code_block(&mut self, _: &Ast, _: &NodeCodeBlock) -> Result<Next, Self::Error> {
+        Ok(Next::SkipChildren)
+    }
+
+    fn enter_html_inline(&mut self, _: &Ast, _: &str) -> Result<Next, Self::Error> {
+        Ok(Next::SkipChildren)
+    }
+
+    fn enter_html_block(&mut self, _: &Ast, _: &NodeHtmlBlock) -> Result<Next, Self::Error> {
+        Ok(Next::SkipChildren)
+    }
+
+    fn enter_heading(&mut self, ast: &Ast, heading: &NodeHeading) -> Result<Next, Self::Error> {
+        if heading.level == 2 {
+            self.section = self.headings.get(&ast.sourcepos.start.line).cloned();
+        }
+
+        Ok(Next::SkipChildren)
+    }
+
+    fn enter_paragraph(&mut self, ast: &Ast) -> Result<Next, Self::Error> {
+        if self.boilerplates.contains(&ast.sourcepos.start.line) {
+            Ok(Next::SkipChildren)
+        } else {
+            Ok(Next::TraverseChildren)
+        }
+    }
+
+    fn enter_text(&mut self, ast: &Ast, txt: &str) -> Result<Next, Self::Error> {
+        self.check(ast, txt)?;
+        Ok(Next::TraverseChildren)
+    }
+}
@@ -0,0 +1,391 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet, SourceAnnotation};
+
+use comrak::nodes::{Ast, NodeCode, NodeCodeBlock, NodeHtmlBlock, NodeLink};
+
+use crate::lints::{Context, Error, Lint};
+use crate::tree::{self, Next, TraverseExt};
+
+use once_cell::sync::Lazy;
+
+use regex::Regex;
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+
+/// English word list, one word per line, sorted by code point.
+///
+/// See `spell/LICENSE.txt` for where it comes from. The list is about 1.3 MB,
+/// and is compiled into every build of this crate (including the WebAssembly
+/// bundle built by `eipw-lint-js`) whether or not the lint is enabled.
+const WORDS: &str = include_str!("spell/words.txt");
+
+/// [`WORDS`], split once and shared between every proposal checked.
+static ENGLISH: Lazy<Vec<&'static str>> = Lazy::new(|| WORDS.lines().collect());
+
+/// [`ENGLISH`], grouped by lowercase first letter and length in characters,
+/// so suggestions only compare against words that could be close enough.
+static BUCKETS: Lazy<HashMap<(char, usize), Vec<&'static str>>> = Lazy::new(|| {
+    let mut buckets: HashMap<_, Vec<_>> = HashMap::new();
+    for word in ENGLISH.iter() {
+        if let Some(key) = bucket(word) {
+            buckets.entry(key).or_default().push(*word);
+        }
+    }
+    buckets
+});
+
+/// Maximum edit distance between an unknown word and a suggestion.
+const MAX_DISTANCE: usize = 2;
+
+/// Maximum number of suggestions offered for each unknown word.
+const MAX_SUGGESTIONS: usize = 3;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Spell<S> {
+    /// Project-specific words (like `calldata` or `SSTORE`) that are accepted
+    /// in addition to the bundled English word list.
+    pub dictionary: Vec<S>,
+}
+
+impl<S> Lint for Spell<S>
+where
+    S: Debug + Display + AsRef<str>,
+{
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let mut project: Vec<&str> = self.dictionary.iter().map(AsRef::as_ref).collect();
+        project.sort_unstable();
+
+        let mut visitor = Visitor {
+            ctx,
+            slug,
+            dictionary: Dictionary {
+                english: &ENGLISH,
+                buckets: &BUCKETS,
+                project,
+            },
+            re: Regex::new(r"\p{Alphabetic}[\p{Alphabetic}\p{N}]*(?:['’]\p{Alphabetic}+)*")
+                .unwrap(),
+            suggestions: Default::default(),
+        };
+
+        ctx.body().traverse().visit(&mut visitor)?;
+
+        Ok(())
+    }
+}
+
+/// Lowercase first letter and length in characters of `word`.
+fn bucket(word: &str) -> Option<(char, usize)> {
+    let first = word.chars().next()?.to_lowercase().next()?;
+    Some((first, word.chars().count()))
+}
+
+struct Dictionary<'d> {
+    english: &'static [&'static str],
+    buckets: &'static HashMap<(char, usize), Vec<&'static str>>,
+    project: Vec<&'d str>,
+}
+
+impl<'d> Dictionary<'d> {
+    fn contains_exact(&self, word: &str) -> bool {
+        self.english.binary_search(&word).is_ok() || self.project.binary_search(&word).is_ok()
+    }
+
+    /// Check if `word` is known, allowing for capitalization at the start of
+    /// a sentence and for words written entirely in upper case.
+    fn contains_cased(&self, word: &str) -> bool {
+        if self.contains_exact(word) {
+            return true;
+        }
+
+        let mut chars = word.chars();
+        let first = match chars.next() {
+            Some(c) => c,
+            None => return true,
+        };
+
+        let rest = chars.as_str();
+
+        if !first.is_uppercase() {
+            return false;
+        }
+
+        let lower = word.to_lowercase();
+
+        if rest.chars().all(|c| !c.is_uppercase()) {
+            // Capitalized, like `Transactions`.
+            return self.contains_exact(&lower);
+        }
+
+        if rest.chars().all(|c| !c.is_lowercase()) {
+            // All caps, like `TRANSACTIONS` or `ETHEREUM`.
+            let capitalized: String = first.to_string() + &rest.to_lowercase();
+            return self.contains_exact(&lower) || self.contains_exact(&capitalized);
+        }
+
+        false
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        let word = word.replace('’', "'");
+
+        if self.contains_cased(&word) {
+            return true;
+        }
+
+        match word.strip_suffix("'s").or_else(|| word.strip_suffix("'S")) {
+            Some(stem) => self.contains_cased(stem),
+            None => false,
+        }
+    }
+
+    fn suggest(&self, word: &str) -> Vec<&str> {
+        let (first, len) = match bucket(word) {
+            Some(k) => k,
+            None => return vec![],
+        };
+
+        let target: Vec<char> = word.to_lowercase().chars().collect();
+
+        let lowercase = word.chars().all(|c| !c.is_uppercase());
+
+        // Only words with the same first letter, and a length within
+        // `MAX_DISTANCE`, are considered.
+        let lengths = len.saturating_sub(MAX_DISTANCE)..=len + MAX_DISTANCE;
+        let english = lengths
+            .filter_map(|l| self.buckets.get(&(first, l)))
+            .flatten();
+
+        let project = self.project.iter().filter(|c| match bucket(c) {
+            Some((f, l)) => f == first && l.abs_diff(len) <= MAX_DISTANCE,
+            None => false,
+        });
+
+        let mut candidates: Vec<_> = project
+            .chain(english)
+            .copied()
+            .filter_map(|c| {
+                let lower: Vec<char> = c.to_lowercase().chars().collect();
+                let d = distance(&target, &lower)?;
+
+                // Among equally distant candidates, prefer ones that keep the
+                // casing and the length of the original.
+                let recased = lowercase && c.chars().any(char::is_uppercase);
+                let length = lower.len().abs_diff(target.len());
+
+                Some(((d, recased, length), c))
+            })
+            .collect();
+
+        // Stable, so project words win ties.
+        candidates.sort_by_key(|(k, _)| *k);
+
+        let mut suggestions: Vec<&str> = Vec::with_capacity(MAX_SUGGESTIONS);
+        for (_, candidate) in candidates {
+            if suggestions.len() == MAX_SUGGESTIONS {
+                break;
+            }
+
+            if !suggestions.contains(&candidate) {
+                suggestions.push(candidate);
+            }
+        }
+
+        suggestions
+    }
+}
+
+/// Optimal string alignment distance between `a` and `b`, or `None` if it
+/// exceeds [`MAX_DISTANCE`].
+fn distance(a: &[char], b: &[char]) -> Option<usize> {
+    let mut prev2: Vec<usize> = vec![0; b.len() + 1];
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut current: Vec<usize> = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        current[0] = i;
+        let mut row_min = i;
+
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut value = (prev[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(prev[j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                value = value.min(prev2[j - 2] + 1);
+            }
+
+            current[j] = value;
+            row_min = row_min.min(value);
+        }
+
+        if row_min > MAX_DISTANCE {
+            return None;
+        }
+
+        std::mem::swap(&mut prev2, &mut prev);
+        std::mem::swap(&mut prev, &mut current);
+    }
+
+    Some(prev[b.len()]).filter(|d| *d <= MAX_DISTANCE)
+}
+
+struct Visitor<'a, 'b, 'c> {
+    ctx: &'c Context<'a, 'b>,
+    slug: &'c str,
+    dictionary: Dictionary<'c>,
+    re: Regex,
+    suggestions: HashMap<String, String>,
+}
+
+impl<'a, 'b, 'c> Visitor<'a, 'b, 'c> {
+    fn suggestions(&mut self, word: &str) -> &str {
+        if !self.suggestions.contains_key(word) {
+            let suggestions = self.dictionary.suggest(word);
+            let label = if suggestions.is_empty() {
+                String::new()
+            } else {
+                let quoted: Vec<_> = suggestions.iter().map(|s| format!("`{}`", s)).collect();
+                format!("did you mean {}?", quoted.join(", "))
+            };
+            self.suggestions.insert(word.to_owned(), label);
+        }
+
+        &self.suggestions[word]
+    }
+
+    fn check(&mut self, ast: &Ast, text: &str) -> Result<(), Error> {
+        let line_start = ast.sourcepos.start.line;
+        let source = self.ctx.line(line_start);
+
+        // Text nodes don't always match the source exactly (escapes, entities,
+        // stripped indentation), so search the line for each word in turn.
+        let mut cursor = source
+            .char_indices()
+            .map(|(idx, _)| idx)
+            .nth(ast.sourcepos.start.column.saturating_sub(1))
+            .unwrap_or(0);
+
+        // The regex can start a match in the middle of a token (like the `x`
+        // in `0x80ac58cd`), so skip matches that follow a letter or digit.
+        let words: Vec<_> = self
+            .re
+            .find_iter(text)
+            .filter(|m| {
+                text[..m.start()]
+                    .chars()
+                    .next_back()
+                    .map_or(true, |c| !c.is_alphanumeric())
+            })
+            .map(|m| m.as_str())
+            .collect();
+
+        for word in words {
+            let position = source[cursor..]
+                .find(word)
+                .map(|idx| idx + cursor)
+                .or_else(|| source.find(word));
+
+            if let Some(position) = position {
+                cursor = position + word.len();
+            }
+
+            if self.dictionary.contains(word) {
+                continue;
+            }
+
+            let annotations = match position {
+                Some(position) => {
+                    let start = source[..position].chars().count();
+                    vec![SourceAnnotation {
+                        annotation_type: self.ctx.annotation_type(),
+                        label: "unknown word",
+                        range: (start, start + word.chars().count()),
+                    }]
+                }
+                None => vec![],
+            };
+
+            let title = format!("unknown word `{}`", word);
+            let help = self.suggestions(word).to_owned();
+
+            let mut footer = vec![];
+            if !help.is_empty() {
+                footer.push(Annotation {
+                    annotation_type: AnnotationType::Help,
+                    id: None,
+                    label: Some(&help),
+                });
+            }
+
+            self.ctx.report(Snippet {
+                title: Some(Annotation {
+                    annotation_type: self.ctx.annotation_type(),
+                    id: Some(self.slug),
+                    label: Some(&title),
+                }),
+                slices: vec![Slice {
+                    fold: false,
+                    line_start,
+                    origin: self.ctx.origin(),
+                    source,
+                    annotations,
+                }],
+                footer,
+                opt: Default::default(),
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, 'b, 'c> tree::Visitor for Visitor<'a, 'b, 'c> {
+    type Error = Error;
+
+    fn enter_front_matter(&mut self, _: &Ast, _: &str) -> Result<Next, Self::Error> {
+        Ok(Next::SkipChildren)
+    }
+
+    fn enter_code(&mut self, _: &Ast, _: &NodeCode) -> Result<Next, Self::Error> {
+        Ok(Next::SkipChildren)
+    }
+
+    fn enter_code_block(&mut self, _: &Ast, _: &NodeCodeBlock) -> Result<Next, Self::Error> {
+        Ok(Next::SkipChildren)
+    }
+
+    fn enter_html_inline(&mut self, _: &Ast, _: &str) -> Result<Next, Self::Error> {
+        Ok(Next::SkipChildren)
+    }
+
+    fn enter_html_block(&mut self, _: &Ast, _: &NodeHtmlBlock) -> Result<Next, Self::Error> {
+        Ok(Next::SkipChildren)
+    }
+
+    fn enter_link(&mut self, _: &Ast, _: &NodeLink) -> Result<Next, Self::Error> {
+        Ok(Next::SkipChildren)
+    }
+
+    fn enter_image(&mut self, _: &Ast, _: &NodeLink) -> Result<Next, Self::Error> {
+        Ok(Next::SkipChildren)
+    }
+
+    fn enter_footnote_reference(&mut self, _: &Ast, _: &str) -> Result<Next, Self::Error> {
+        Ok(Next::SkipChildren)
+    }
+
+    fn enter_text(&mut self, ast: &Ast, txt: &str) -> Result<Next, Self::Error> {
+        self.check(ast, txt)?;
+        Ok(Next::TraverseChildren)
+    }
+}
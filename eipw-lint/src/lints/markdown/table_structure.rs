@@ -0,0 +1,229 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet, SourceAnnotation};
+
+use comrak::nodes::{Ast, TableAlignment};
+
+use crate::lints::{Context, Error, Lint};
+use crate::tree::{self, Next, TraverseExt};
+
+use regex::Regex;
+
+use serde::{Deserialize, Serialize};
+
+use std::fmt::Debug;
+
+/// Reports tables that GitHub and Jekyll would render differently: rows with
+/// the wrong number of cells, empty header cells, and rows of cells missing a
+/// header.
+///
+/// When `alignment` is set, also reports would-be tables whose alignment row
+/// has a different number of cells than the header, which GitHub doesn't
+/// render as a table at all.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TableStructure {
+    pub alignment: bool,
+}
+
+impl Lint for TableStructure {
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let mut visitor = Visitor {
+            ctx,
+            slug,
+            alignment: self.alignment,
+            header: 0,
+            delimiter: Regex::new(r"^\|?\s*:?-+:?\s*(\|\s*:?-+:?\s*)*\|?$").unwrap(),
+        };
+
+        ctx.body().traverse().visit(&mut visitor)?;
+
+        Ok(())
+    }
+}
+
+/// Character ranges of the cells in a table row, excluding the pipes.
+fn cells(row: &str) -> Vec<(usize, usize)> {
+    let mut pipes = vec![];
+    let mut escaped = false;
+
+    for (idx, c) in row.chars().enumerate() {
+        if c == '|' && !escaped {
+            pipes.push(idx);
+        }
+        escaped = c == '\\' && !escaped;
+    }
+
+    let mut start = row.chars().take_while(|c| c.is_whitespace()).count();
+    let mut end = row.trim_end().chars().count();
+
+    if pipes.first() == Some(&start) {
+        pipes.remove(0);
+        start += 1;
+    }
+
+    if end > start && pipes.last() == Some(&(end - 1)) {
+        pipes.pop();
+        end -= 1;
+    }
+
+    let mut result = vec![];
+    for pipe in pipes {
+        result.push((start, pipe));
+        start = pipe + 1;
+    }
+    result.push((start, end));
+
+    result
+}
+
+fn count(cells: usize) -> String {
+    match cells {
+        1 => "1 cell".into(),
+        n => format!("{} cells", n),
+    }
+}
+
+struct Visitor<'a, 'b, 'c> {
+    ctx: &'c Context<'a, 'b>,
+    slug: &'c str,
+    alignment: bool,
+    header: usize,
+    delimiter: Regex,
+}
+
+impl<'a, 'b, 'c> Visitor<'a, 'b, 'c> {
+    /// Source of a line, without the container prefix (eg. `> `) before
+    /// `column`.
+    fn line(&self, line: usize, column: usize) -> (&'a str, usize) {
+        let source = self.ctx.line(line);
+        let offset = column.saturating_sub(1);
+        match source.get(offset..) {
+            Some(s) => (s, source[..offset].chars().count()),
+            None => (source, 0),
+        }
+    }
+
+    fn report(
+        &self,
+        label: &str,
+        line_start: usize,
+        annotations: Vec<SourceAnnotation>,
+        help: Option<&str>,
+    ) -> Result<(), Error> {
+        let footer = match help {
+            Some(h) => vec![Annotation {
+                annotation_type: AnnotationType::Help,
+                id: None,
+                label: Some(h),
+            }],
+            None => vec![],
+        };
+
+        self.ctx.report(Snippet {
+            title: Some(Annotation {
+                annotation_type: self.ctx.annotation_type(),
+                id: Some(self.slug),
+                label: Some(label),
+            }),
+            slices: vec![Slice {
+                fold: false,
+                line_start,
+                origin: self.ctx.origin(),
+                source: self.ctx.line(line_start),
+                annotations,
+            }],
+            footer,
+            opt: Default::default(),
+        })
+    }
+}
+
+impl<'a, 'b, 'c> tree::Visitor for Visitor<'a, 'b, 'c> {
+    type Error = Error;
+
+    fn enter_table(&mut self, _: &Ast, _: &[TableAlignment]) -> Result<Next, Self::Error> {
+        self.header = 0;
+        Ok(Next::TraverseChildren)
+    }
+
+    fn enter_table_row(&mut self, ast: &Ast, header: bool) -> Result<Next, Self::Error> {
+        let line_start = ast.sourcepos.start.line;
+        let (source, offset) = self.line(line_start, ast.sourcepos.start.column);
+        let cells = cells(source);
+
+        if header {
+            self.header = cells.len();
+
+            for (start, end) in cells {
+                let text: String = source.chars().skip(start).take(end - start).collect();
+                if !text.trim().is_empty() {
+                    continue;
+                }
+
+                let annotations = vec![SourceAnnotation {
+                    annotation_type: self.ctx.annotation_type(),
+                    label: "",
+                    range: (offset + start, offset + end),
+                }];
+
+                self.report("table header cell is empty", line_start, annotations, None)?;
+            }
+        } else if cells.len() != self.header {
+            let label = format!(
+                "table row has {}, but the header has {}",
+                count(cells.len()),
+                self.header
+            );
+            self.report(&label, line_start, vec![], None)?;
+        }
+
+        Ok(Next::SkipChildren)
+    }
+
+    fn enter_paragraph(&mut self, ast: &Ast) -> Result<Next, Self::Error> {
+        let first = ast.sourcepos.start.line;
+        let last = ast.sourcepos.end.line;
+
+        if last <= first {
+            return Ok(Next::SkipChildren);
+        }
+
+        let lines: Vec<_> = (first..=last)
+            .map(|n| self.line(n, ast.sourcepos.start.column).0.trim())
+            .collect();
+
+        if !lines.iter().all(|l| l.starts_with('|')) {
+            return Ok(Next::SkipChildren);
+        }
+
+        let header = cells(lines[0]).len();
+
+        if self.delimiter.is_match(lines[1]) {
+            if self.alignment {
+                let label = format!(
+                    "table alignment row has {}, but the header has {}",
+                    count(cells(lines[1]).len()),
+                    header
+                );
+                self.report(
+                    &label,
+                    first + 1,
+                    vec![],
+                    Some("GitHub doesn't render this as a table"),
+                )?;
+            }
+        } else {
+            let help = format!(
+                "add an alignment row (like `|{}`) after the first row",
+                " --- |".repeat(header)
+            );
+            self.report("table is missing a header row", first, vec![], Some(&help))?;
+        }
+
+        Ok(Next::SkipChildren)
+    }
+}
@@ -0,0 +1,364 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet, SourceAnnotation};
+
+use comrak::nodes::NodeValue;
+
+use crate::lints::{Context, Error, Lint};
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashSet;
+use std::fmt::Debug;
+
+/// Reports carriage returns (`\r\n` or a lone `\r`) used as line endings.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LineEndings;
+
+/// Reports whitespace at the end of lines, outside of code blocks.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TrailingWhitespace;
+
+/// Reports sources that don't end with a newline.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FinalNewline;
+
+/// Reports more than one newline at the end of the source.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TrailingBlankLines;
+
+/// Reports tab characters outside of code blocks.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HardTabs;
+
+/// Reports non-breaking spaces (U+00A0) anywhere in the source.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NonBreakingSpaces;
+
+/// A line of the source, without its line ending.
+pub(super) struct Line<'a> {
+    pub(super) number: usize,
+    pub(super) offset: usize,
+    pub(super) text: &'a str,
+}
+
+/// Lines of `source`, along with the byte offset each starts at.
+pub(super) fn lines(source: &str) -> impl Iterator<Item = Line<'_>> {
+    let mut offset = 0;
+    source.split('\n').enumerate().map(move |(idx, text)| {
+        let line = Line {
+            number: idx + 1,
+            offset,
+            text: text.strip_suffix('\r').unwrap_or(text),
+        };
+        offset += text.len() + 1;
+        line
+    })
+}
+
+/// Lines belonging to code blocks, including fences.
+pub(super) fn code_lines(ctx: &Context) -> HashSet<usize> {
+    let mut lines = HashSet::new();
+
+    for node in ctx.body().descendants() {
+        let data = node.data.borrow();
+        if let NodeValue::CodeBlock(_) = data.value {
+            lines.extend(data.sourcepos.start.line..=data.sourcepos.end.line);
+        }
+    }
+
+    lines
+}
+
+fn char_range(text: &str, start: usize, end: usize) -> (usize, usize) {
+    (text[..start].chars().count(), text[..end].chars().count())
+}
+
+/// Source of a line, without its line ending.
+fn line_source<'a>(ctx: &Context<'a, '_>, line: usize) -> &'a str {
+    let source = ctx.line(line);
+    source.strip_suffix('\r').unwrap_or(source)
+}
+
+fn report(
+    ctx: &Context,
+    slug: &str,
+    label: &str,
+    line: usize,
+    source: &str,
+    ranges: &[(usize, usize)],
+    help: &str,
+) -> Result<(), Error> {
+    let annotations = ranges
+        .iter()
+        .map(|range| SourceAnnotation {
+            annotation_type: ctx.annotation_type(),
+            label: "",
+            range: *range,
+        })
+        .collect();
+
+    ctx.report(Snippet {
+        title: Some(Annotation {
+            annotation_type: ctx.annotation_type(),
+            id: Some(slug),
+            label: Some(label),
+        }),
+        slices: vec![Slice {
+            fold: false,
+            line_start: line,
+            origin: ctx.origin(),
+            source,
+            annotations,
+        }],
+        footer: vec![Annotation {
+            annotation_type: AnnotationType::Help,
+            id: None,
+            label: Some(help),
+        }],
+        opt: Default::default(),
+    })
+}
+
+impl Lint for LineEndings {
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let source = ctx.source();
+        let bytes = source.as_bytes();
+
+        let mut first = None;
+        let mut affected = 0;
+
+        let mut line = 1;
+        let mut scanned = 0;
+
+        for (idx, _) in source.match_indices('\r') {
+            let previous = line;
+            line += source[scanned..idx].matches('\n').count();
+            scanned = idx;
+
+            let crlf = bytes.get(idx + 1) == Some(&b'\n');
+            if first.is_none() || line != previous {
+                affected += 1;
+            }
+            first.get_or_insert((line, crlf));
+
+            let replacement = if crlf { "" } else { "\n" };
+            ctx.fix(idx..idx + 1, replacement)?;
+        }
+
+        // Only the first line is shown, but the fix covers all of them.
+        let (line, crlf) = match first {
+            Some(f) => f,
+            None => return Ok(()),
+        };
+
+        let ending = match crlf {
+            true => "\\r\\n",
+            false => "\\r",
+        };
+
+        let label = match affected - 1 {
+            0 => format!("line ends with `{}`", ending),
+            1 => format!("line ends with `{}` (1 more line is affected)", ending),
+            n => format!(
+                "line ends with `{}` ({} more lines are affected)",
+                ending, n
+            ),
+        };
+
+        report(
+            ctx,
+            slug,
+            &label,
+            line,
+            line_source(ctx, line),
+            &[],
+            "use `\\n` line endings throughout the file",
+        )
+    }
+}
+
+impl Lint for TrailingWhitespace {
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let code = code_lines(ctx);
+
+        // Lines followed by another line of the same paragraph, where two or
+        // more trailing spaces are a hard line break.
+        let mut breaks = HashSet::new();
+        for node in ctx.body().descendants() {
+            let data = node.data.borrow();
+            if let NodeValue::Paragraph = data.value {
+                breaks.extend(data.sourcepos.start.line..data.sourcepos.end.line);
+            }
+        }
+
+        for line in lines(ctx.source()) {
+            if code.contains(&line.number) {
+                continue;
+            }
+
+            let trimmed = line.text.trim_end();
+            if trimmed.len() == line.text.len() {
+                continue;
+            }
+
+            let trailing = &line.text[trimmed.len()..];
+            let range = line.offset + trimmed.len()..line.offset + line.text.len();
+
+            let hard_break = breaks.contains(&line.number)
+                && trailing.len() >= 2
+                && trailing.bytes().all(|b| b == b' ');
+
+            let (replacement, help) = match hard_break {
+                true => ("\\", "use a backslash (`\\`) to break the line"),
+                false => ("", "remove the trailing whitespace"),
+            };
+
+            ctx.fix(range, replacement)?;
+
+            report(
+                ctx,
+                slug,
+                "line has trailing whitespace",
+                line.number,
+                line.text,
+                &[char_range(line.text, trimmed.len(), line.text.len())],
+                help,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Lint for FinalNewline {
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let source = ctx.source();
+        if source.is_empty() || source.ends_with('\n') {
+            return Ok(());
+        }
+
+        ctx.fix(source.len()..source.len(), "\n")?;
+
+        let line = source.matches('\n').count() + 1;
+        report(
+            ctx,
+            slug,
+            "file does not end with a newline",
+            line,
+            line_source(ctx, line),
+            &[],
+            "add a newline after the last line",
+        )
+    }
+}
+
+impl Lint for TrailingBlankLines {
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let source = ctx.source();
+        let content = source.trim_end();
+        let trailing = &source[content.len()..];
+
+        if trailing.matches('\n').count() < 2 {
+            return Ok(());
+        }
+
+        ctx.fix(content.len()..source.len(), "\n")?;
+
+        let line = content.matches('\n').count() + 1;
+        report(
+            ctx,
+            slug,
+            "file ends with blank lines",
+            line,
+            line_source(ctx, line),
+            &[],
+            "end the file with a single newline",
+        )
+    }
+}
+
+impl Lint for HardTabs {
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let code = code_lines(ctx);
+
+        for line in lines(ctx.source()) {
+            if code.contains(&line.number) || !line.text.contains('\t') {
+                continue;
+            }
+
+            // Tabs are displayed with zero width, so show the line as it
+            // would be once fixed, and annotate the expanded tabs instead.
+            let mut expanded = String::with_capacity(line.text.len());
+            let mut ranges = vec![];
+            let mut column = 0;
+
+            for (idx, c) in line.text.char_indices() {
+                if c != '\t' {
+                    expanded.push(c);
+                    column += 1;
+                    continue;
+                }
+
+                // Expand to the next multiple of four columns.
+                let width = 4 - column % 4;
+                let spaces = " ".repeat(width);
+
+                let start = line.offset + idx;
+                ctx.fix(start..start + 1, spaces.as_str())?;
+
+                expanded.push_str(&spaces);
+                ranges.push((column, column + width));
+                column += width;
+            }
+
+            report(
+                ctx,
+                slug,
+                "line contains a hard tab",
+                line.number,
+                &expanded,
+                &ranges,
+                "indent with spaces instead",
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Lint for NonBreakingSpaces {
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        for line in lines(ctx.source()) {
+            let mut ranges = vec![];
+
+            for (idx, _) in line.text.match_indices('\u{a0}') {
+                let start = line.offset + idx;
+                ctx.fix(start..start + '\u{a0}'.len_utf8(), " ")?;
+
+                let start = line.text[..idx].chars().count();
+                ranges.push((start, start + 1));
+            }
+
+            if ranges.is_empty() {
+                continue;
+            }
+
+            report(
+                ctx,
+                slug,
+                "line contains a non-breaking space",
+                line.number,
+                line.text,
+                &ranges,
+                "use a regular space, or `&nbsp;` where the line must not break",
+            )?;
+        }
+
+        Ok(())
+    }
+}
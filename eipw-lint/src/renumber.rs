@@ -0,0 +1,181 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Moves a proposal to a new number, keeping the file name, asset directory,
+//! preamble, and self-links consistent.
+
+use crate::lints::DefaultLint;
+
+use eipw_preamble::{OwnedPreamble, Preamble, SourceError};
+
+use regex::{Captures, Regex};
+
+use serde::{Deserialize, Serialize};
+
+use snafu::{OptionExt, Snafu};
+
+#[derive(Snafu, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[snafu(context(false), display("{}", source))]
+    Source { source: SourceError },
+    #[snafu(display("preamble header `{}` is missing", name))]
+    Missing { name: String },
+    #[snafu(display("preamble header `{}` is not a number: `{}`", name, value))]
+    Number { name: String, value: String },
+}
+
+/// Naming scheme for proposals, configured from the `preamble::FileName` and
+/// `markdown::Assets*` lints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Renumber<S> {
+    /// Preamble header containing the proposal's number.
+    pub name: S,
+
+    /// File names are `{prefix}{number}{suffix}`.
+    pub prefix: S,
+    pub suffix: S,
+
+    /// Directory containing asset directories, relative to the proposal.
+    /// Asset directories are named `{prefix}{number}`.
+    pub assets: S,
+}
+
+impl<S> Default for Renumber<S>
+where
+    S: From<&'static str>,
+{
+    fn default() -> Self {
+        Self {
+            name: "eip".into(),
+            prefix: "eip-".into(),
+            suffix: ".md".into(),
+            assets: "../assets".into(),
+        }
+    }
+}
+
+impl<S> Renumber<S>
+where
+    S: Clone + From<&'static str>,
+{
+    /// Builds a naming scheme from the configuration of the
+    /// `preamble::FileName` and `markdown::Assets*` lints in `lints`, falling
+    /// back to [`Renumber::default`].
+    pub fn from_lints<'l, I>(lints: I) -> Self
+    where
+        I: IntoIterator<Item = &'l DefaultLint<S>>,
+        S: 'l,
+    {
+        let mut renumber = Self::default();
+
+        for lint in lints {
+            match lint {
+                DefaultLint::PreambleFileName(l) => {
+                    renumber.name = l.name.clone();
+                    renumber.prefix = l.prefix.clone();
+                    renumber.suffix = l.suffix.clone();
+                }
+                DefaultLint::MarkdownAssetsForeign(l) => renumber.assets = l.assets.clone(),
+                DefaultLint::MarkdownAssetsNumber(l) => renumber.assets = l.assets.clone(),
+                DefaultLint::MarkdownAssetsUnused(l) => renumber.assets = l.assets.clone(),
+                DefaultLint::MarkdownAssetsSize(l) => renumber.assets = l.assets.clone(),
+                _ => (),
+            }
+        }
+
+        renumber
+    }
+}
+
+impl<S> Renumber<S>
+where
+    S: AsRef<str>,
+{
+    /// Name of the file for proposal `number`.
+    pub fn file_name(&self, number: u64) -> String {
+        format!("{}{}{}", self.prefix.as_ref(), number, self.suffix.as_ref())
+    }
+
+    /// Path of the asset directory for proposal `number`, relative to the
+    /// directory containing the proposal.
+    pub fn assets_dir(&self, number: u64) -> String {
+        format!(
+            "{}/{}{}",
+            self.assets.as_ref(),
+            self.prefix.as_ref(),
+            number
+        )
+    }
+
+    fn preamble(&self, source: &str) -> Result<OwnedPreamble, Error> {
+        Ok(OwnedPreamble::from_source(source)?.0)
+    }
+
+    /// Returns the current number of the proposal in `source`.
+    pub fn number(&self, source: &str) -> Result<u64, Error> {
+        let preamble = self.preamble(source)?;
+        let name = self.name.as_ref();
+
+        let field = preamble.by_name(name).context(MissingSnafu { name })?;
+
+        field.value().parse().ok().context(NumberSnafu {
+            name,
+            value: field.value(),
+        })
+    }
+
+    /// Returns `source` with its preamble header and self-links changed to
+    /// refer to proposal `number`.
+    pub fn rewrite(&self, source: &str, number: u64) -> Result<String, Error> {
+        let old = self.number(source)?;
+
+        let mut preamble = self.preamble(source)?;
+        preamble.set(self.name.as_ref(), number.to_string());
+
+        let output = preamble.splice(source).map_err(SourceError::from)?;
+
+        // `splice` leaves everything after the preamble untouched.
+        let (_, body) = Preamble::split(&output).map_err(SourceError::from)?;
+        let head = &output[..output.len() - body.len()];
+
+        let old_file = self.file_name(old);
+        let new_file = self.file_name(number);
+        let old_dir = format!("{}{}", self.prefix.as_ref(), old);
+        let new_dir = format!("{}{}", self.prefix.as_ref(), number);
+
+        // Inline link destinations, link reference definitions, and `src` or
+        // `href` attributes in HTML.
+        let link = Regex::new(
+            r#"(?m)(\]\(\s*<?|^ {0,3}\[[^\]]+\]:\s*<?|\b(?i:src|href)\s*=\s*["']?)([^\s)>"']+)"#,
+        )
+        .unwrap();
+
+        let body = link.replace_all(body, |c: &Captures| {
+            let (path, fragment) = match c[2].find(['#', '?']) {
+                Some(idx) => c[2].split_at(idx),
+                None => (&c[2], ""),
+            };
+
+            let path: Vec<_> = path
+                .split('/')
+                .map(|component| {
+                    if component == old_file {
+                        new_file.as_str()
+                    } else if component == old_dir {
+                        new_dir.as_str()
+                    } else {
+                        component
+                    }
+                })
+                .collect();
+
+            format!("{}{}{}", &c[1], path.join("/"), fragment)
+        });
+
+        Ok(format!("{}{}", head, body))
+    }
+}
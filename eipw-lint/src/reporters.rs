@@ -5,15 +5,29 @@
  */
 
 pub mod additional_help;
+pub mod baseline;
+pub mod checkstyle;
 pub mod count;
+pub mod fixes;
+pub mod github;
 pub mod json;
+pub mod junit;
 pub mod null;
 pub mod text;
 
+mod xml;
+
 use annotate_snippets::snippet::Snippet;
 
+use crate::fix::Fix;
+
 pub use self::additional_help::AdditionalHelp;
+pub use self::baseline::Baseline;
+pub use self::checkstyle::Checkstyle;
+pub use self::fixes::Fixes;
+pub use self::github::Github;
 pub use self::json::Json;
+pub use self::junit::Junit;
 pub use self::null::Null;
 pub use self::text::Text;
 
@@ -49,4 +63,10 @@ impl Error {
 
 pub trait Reporter {
     fn report(&self, snippet: Snippet<'_>) -> Result<(), Error>;
+
+    /// Receive a machine-applicable fix. Reporters that can't make use of
+    /// fixes ignore them.
+    fn fix(&self, _fix: Fix) -> Result<(), Error> {
+        Ok(())
+    }
 }
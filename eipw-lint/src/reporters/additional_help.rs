@@ -9,6 +9,8 @@ use annotate_snippets::snippet::{Annotation, AnnotationType, Snippet};
 use std::collections::HashSet;
 use std::sync::Mutex;
 
+use crate::fix::Fix;
+
 use super::{Error, Reporter};
 
 #[derive(Debug)]
@@ -83,4 +85,8 @@ where
             self.inner.report(snippet)
         }
     }
+
+    fn fix(&self, fix: Fix) -> Result<(), Error> {
+        self.inner.fix(fix)
+    }
 }
@@ -0,0 +1,157 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::Snippet;
+
+use serde::{Deserialize, Serialize};
+
+use sha3::{Digest, Keccak256};
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::fix::Fix;
+
+use super::{Error, Reporter};
+
+/// A diagnostic recorded in a baseline.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Entry {
+    /// File the diagnostic was reported in.
+    pub origin: Option<String>,
+
+    /// Slug of the lint that reported the diagnostic.
+    pub slug: Option<String>,
+
+    /// Hash of the diagnostic's message and source text. Line numbers aren't
+    /// included, so the fingerprint survives lines being added or removed
+    /// elsewhere in the file.
+    pub fingerprint: String,
+}
+
+impl Entry {
+    pub fn new(snippet: &Snippet<'_>) -> Self {
+        let mut hasher = Keccak256::new();
+
+        let title = snippet.title.as_ref();
+        hasher.update(title.and_then(|t| t.label).unwrap_or_default());
+
+        for slice in &snippet.slices {
+            hasher.update([0]);
+            hasher.update(slice.source);
+
+            for annotation in &slice.annotations {
+                hasher.update([0]);
+                hasher.update(annotation.label);
+                hasher.update(annotation.range.0.to_le_bytes());
+                hasher.update(annotation.range.1.to_le_bytes());
+            }
+        }
+
+        let mut fingerprint = String::with_capacity(16);
+        for byte in &hasher.finalize()[..8] {
+            write!(fingerprint, "{:02x}", byte).unwrap();
+        }
+
+        Self {
+            origin: snippet
+                .slices
+                .iter()
+                .find_map(|s| s.origin)
+                .map(str::to_owned),
+            slug: title.and_then(|t| t.id).map(str::to_owned),
+            fingerprint,
+        }
+    }
+}
+
+/// Suppresses diagnostics recorded in a baseline, passing only new ones
+/// through to `inner`.
+///
+/// Every diagnostic (suppressed or not) is remembered, so a new baseline can
+/// be written with [`Baseline::seen`].
+#[derive(Debug, Default)]
+pub struct Baseline<T> {
+    inner: T,
+    known: RefCell<BTreeMap<Entry, usize>>,
+    seen: RefCell<Vec<Entry>>,
+}
+
+impl<T> Reporter for Baseline<T>
+where
+    T: Reporter,
+{
+    fn report(&self, snippet: Snippet<'_>) -> Result<(), Error> {
+        let entry = Entry::new(&snippet);
+        self.seen.borrow_mut().push(entry.clone());
+
+        if let Some(remaining) = self.known.borrow_mut().get_mut(&entry) {
+            if *remaining > 0 {
+                *remaining -= 1;
+                return Ok(());
+            }
+        }
+
+        self.inner.report(snippet)
+    }
+
+    fn fix(&self, fix: Fix) -> Result<(), Error> {
+        self.inner.fix(fix)
+    }
+}
+
+impl<T> Baseline<T> {
+    /// Suppresses diagnostics matching `known`. A diagnostic that occurs more
+    /// often than it appears in `known` is reported for each extra occurrence.
+    pub fn new<I>(inner: T, known: I) -> Self
+    where
+        I: IntoIterator<Item = Entry>,
+    {
+        let mut counts = BTreeMap::new();
+        for entry in known {
+            *counts.entry(entry).or_default() += 1;
+        }
+
+        Self {
+            inner,
+            known: RefCell::new(counts),
+            seen: Default::default(),
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Every diagnostic reported so far, including suppressed ones, sorted.
+    pub fn seen(&self) -> Vec<Entry> {
+        let mut seen = self.seen.borrow().clone();
+        seen.sort();
+        seen
+    }
+
+    /// Entries from the baseline that haven't been reported (yet), limited to
+    /// those with an origin in `checked`.
+    pub fn stale<I, S>(&self, checked: I) -> Vec<Entry>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let checked: Vec<_> = checked.into_iter().collect();
+
+        self.known
+            .borrow()
+            .iter()
+            .filter(|(entry, _)| {
+                checked
+                    .iter()
+                    .any(|c| entry.origin.as_deref() == Some(c.as_ref()))
+            })
+            .flat_map(|(entry, count)| std::iter::repeat(entry.clone()).take(*count))
+            .collect()
+    }
+}
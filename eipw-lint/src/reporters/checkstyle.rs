@@ -0,0 +1,75 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{AnnotationType, Snippet};
+
+use std::cell::RefCell;
+use std::fmt;
+
+use super::xml::{escape_attribute, group, Diagnostic};
+use super::{Error, Reporter};
+
+/// Collects diagnostics to write as Checkstyle XML, grouped by file and then
+/// by lint.
+///
+/// Errors and warnings keep their severity, and every other kind of
+/// diagnostic is written with a severity of `info`.
+#[derive(Debug, Default)]
+pub struct Checkstyle {
+    reports: RefCell<Vec<Diagnostic>>,
+}
+
+impl Reporter for Checkstyle {
+    fn report(&self, snippet: Snippet<'_>) -> Result<(), Error> {
+        self.reports.borrow_mut().push(Diagnostic::new(snippet));
+        Ok(())
+    }
+}
+
+impl fmt::Display for Checkstyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reports = self.reports.borrow();
+
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(f, r#"<checkstyle version="4.3">"#)?;
+
+        for (file, slugs) in group(&reports) {
+            writeln!(f, r#"  <file name="{}">"#, escape_attribute(file))?;
+
+            for (slug, diagnostics) in slugs {
+                for diagnostic in diagnostics {
+                    let severity = match diagnostic.level {
+                        Some(AnnotationType::Error) => "error",
+                        Some(AnnotationType::Warning) => "warning",
+                        _ => "info",
+                    };
+
+                    write!(f, "    <error")?;
+
+                    if let Some(line) = diagnostic.line {
+                        write!(f, r#" line="{}""#, line)?;
+                    }
+
+                    if let Some(column) = diagnostic.column {
+                        write!(f, r#" column="{}""#, column)?;
+                    }
+
+                    writeln!(
+                        f,
+                        r#" severity="{}" message="{}" source="eipw.{}"/>"#,
+                        severity,
+                        escape_attribute(&diagnostic.message),
+                        escape_attribute(slug),
+                    )?;
+                }
+            }
+
+            writeln!(f, "  </file>")?;
+        }
+
+        writeln!(f, "</checkstyle>")
+    }
+}
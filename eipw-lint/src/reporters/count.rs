@@ -8,6 +8,8 @@ use annotate_snippets::snippet::{AnnotationType, Snippet};
 
 use std::cell::RefCell;
 
+use crate::fix::Fix;
+
 use super::{Error, Reporter};
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -45,6 +47,10 @@ where
 
         self.inner.report(snippet)
     }
+
+    fn fix(&self, fix: Fix) -> Result<(), Error> {
+        self.inner.fix(fix)
+    }
 }
 
 impl<T> Count<T> {
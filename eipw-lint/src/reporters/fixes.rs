@@ -0,0 +1,56 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::Snippet;
+
+use std::cell::RefCell;
+
+use crate::fix::Fix;
+
+use super::{Error, Reporter};
+
+/// Collects the fixes suggested by lints, while passing reports through to
+/// `inner`.
+#[derive(Debug, Default)]
+pub struct Fixes<T> {
+    inner: T,
+    fixes: RefCell<Vec<Fix>>,
+}
+
+impl<T> Reporter for Fixes<T>
+where
+    T: Reporter,
+{
+    fn report(&self, snippet: Snippet<'_>) -> Result<(), Error> {
+        self.inner.report(snippet)
+    }
+
+    fn fix(&self, fix: Fix) -> Result<(), Error> {
+        self.fixes.borrow_mut().push(fix.clone());
+        self.inner.fix(fix)
+    }
+}
+
+impl<T> Fixes<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            fixes: Default::default(),
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    pub fn into_parts(self) -> (T, Vec<Fix>) {
+        (self.inner, self.fixes.into_inner())
+    }
+
+    pub fn fixes(&self) -> Vec<Fix> {
+        self.fixes.borrow().clone()
+    }
+}
@@ -0,0 +1,135 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{AnnotationType, Snippet};
+
+use std::cell::RefCell;
+use std::fmt::{Debug, Write};
+
+use super::{Error, Reporter};
+
+/// Writes diagnostics as GitHub Actions workflow commands (eg.
+/// `::error file=eip-1.md,line=2,col=5,title=preamble-trim::...`), so they
+/// appear inline on pull requests.
+#[derive(Debug, Default)]
+pub struct Github<W> {
+    inner: RefCell<W>,
+}
+
+/// Escapes the message of a workflow command.
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapes the value of a workflow command property.
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Line and column (both one-indexed) of the character at `offset` in
+/// `source`, relative to the start of `source`.
+pub(super) fn position(source: &str, offset: usize) -> (usize, usize) {
+    let mut line = 0;
+    let mut column = 1;
+
+    for c in source.chars().take(offset) {
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+
+    (line, column)
+}
+
+impl<W> Reporter for Github<W>
+where
+    W: Write,
+{
+    fn report(&self, snippet: Snippet<'_>) -> Result<(), Error> {
+        let command = match snippet.title.as_ref().map(|t| t.annotation_type) {
+            Some(AnnotationType::Error) => "error",
+            Some(AnnotationType::Warning) => "warning",
+            _ => "notice",
+        };
+
+        let mut properties = Vec::new();
+
+        if let Some(slice) = snippet.slices.first() {
+            if let Some(origin) = slice.origin {
+                properties.push(format!("file={}", escape_property(origin)));
+            }
+
+            match slice.annotations.first() {
+                Some(annotation) => {
+                    let (start, end) = annotation.range;
+                    let (start_line, start_col) = position(slice.source, start);
+
+                    // Annotation ranges are exclusive, but GitHub's are inclusive.
+                    let (end_line, end_col) = position(slice.source, end.max(start + 1) - 1);
+
+                    properties.push(format!("line={}", slice.line_start + start_line));
+                    properties.push(format!("col={}", start_col));
+
+                    if end_line == start_line {
+                        properties.push(format!("endColumn={}", end_col));
+                    } else {
+                        properties.push(format!("endLine={}", slice.line_start + end_line));
+                    }
+                }
+                None => properties.push(format!("line={}", slice.line_start)),
+            }
+        }
+
+        if let Some(id) = snippet.title.as_ref().and_then(|t| t.id) {
+            properties.push(format!("title={}", escape_property(id)));
+        }
+
+        let mut message = snippet
+            .title
+            .as_ref()
+            .and_then(|t| t.label)
+            .unwrap_or_default()
+            .to_owned();
+
+        for footer in &snippet.footer {
+            if let Some(label) = footer.label {
+                let kind = match footer.annotation_type {
+                    AnnotationType::Error => "error",
+                    AnnotationType::Warning => "warning",
+                    AnnotationType::Info => "info",
+                    AnnotationType::Note => "note",
+                    AnnotationType::Help => "help",
+                };
+                write!(message, "\n{}: {}", kind, label).unwrap();
+            }
+        }
+
+        let mut inner = self.inner.borrow_mut();
+
+        write!(inner, "::{}", command).map_err(Error::new)?;
+        if !properties.is_empty() {
+            write!(inner, " {}", properties.join(",")).map_err(Error::new)?;
+        }
+        writeln!(inner, "::{}", escape_data(&message)).map_err(Error::new)
+    }
+}
+
+impl<W> Github<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner: inner.into(),
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner.into_inner()
+    }
+}
@@ -0,0 +1,90 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use annotate_snippets::snippet::{AnnotationType, Snippet};
+
+use std::cell::RefCell;
+use std::fmt;
+
+use super::xml::{escape, escape_attribute, group, Diagnostic};
+use super::{Error, Reporter};
+
+/// Collects diagnostics to write as JUnit XML, with one test suite per file
+/// and one test case per lint.
+///
+/// Errors become `<failure>` elements. Warnings (and other diagnostics) don't
+/// fail the test case, and are written to its `<system-out>` instead.
+#[derive(Debug, Default)]
+pub struct Junit {
+    reports: RefCell<Vec<Diagnostic>>,
+}
+
+impl Reporter for Junit {
+    fn report(&self, snippet: Snippet<'_>) -> Result<(), Error> {
+        self.reports.borrow_mut().push(Diagnostic::new(snippet));
+        Ok(())
+    }
+}
+
+impl fmt::Display for Junit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reports = self.reports.borrow();
+
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(f, r#"<testsuites name="eipw">"#)?;
+
+        for (file, slugs) in group(&reports) {
+            let failures = slugs
+                .values()
+                .filter(|d| d.iter().any(|d| d.level == Some(AnnotationType::Error)))
+                .count();
+
+            writeln!(
+                f,
+                r#"  <testsuite name="{}" tests="{}" failures="{}" errors="0">"#,
+                escape_attribute(file),
+                slugs.len(),
+                failures,
+            )?;
+
+            for (slug, diagnostics) in slugs {
+                writeln!(
+                    f,
+                    r#"    <testcase name="{}" classname="{}">"#,
+                    escape_attribute(slug),
+                    escape_attribute(file),
+                )?;
+
+                let mut output = String::new();
+
+                for diagnostic in diagnostics {
+                    if diagnostic.level != Some(AnnotationType::Error) {
+                        output.push_str(&diagnostic.formatted);
+                        output.push('\n');
+                        continue;
+                    }
+
+                    writeln!(
+                        f,
+                        r#"      <failure type="error" message="{}">{}</failure>"#,
+                        escape_attribute(&diagnostic.message),
+                        escape(&diagnostic.formatted),
+                    )?;
+                }
+
+                if !output.is_empty() {
+                    writeln!(f, "      <system-out>{}</system-out>", escape(&output))?;
+                }
+
+                writeln!(f, "    </testcase>")?;
+            }
+
+            writeln!(f, "  </testsuite>")?;
+        }
+
+        writeln!(f, "</testsuites>")
+    }
+}
@@ -0,0 +1,116 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Helpers shared by the XML-based reporters.
+
+use annotate_snippets::display_list::DisplayList;
+use annotate_snippets::snippet::{AnnotationType, Snippet};
+
+use std::collections::BTreeMap;
+
+use super::github::position;
+
+/// The parts of a [`Snippet`] needed to write it as XML.
+#[derive(Debug)]
+pub(super) struct Diagnostic {
+    pub file: String,
+    pub slug: String,
+    pub level: Option<AnnotationType>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+
+    /// The title, followed by any footers, one per line.
+    pub message: String,
+
+    /// The complete diagnostic, as written by [`super::Text`].
+    pub formatted: String,
+}
+
+impl Diagnostic {
+    pub fn new(snippet: Snippet<'_>) -> Self {
+        let title = snippet.title.as_ref();
+
+        let mut message = title.and_then(|t| t.label).unwrap_or_default().to_owned();
+
+        for footer in &snippet.footer {
+            if let Some(label) = footer.label {
+                message.push('\n');
+                message.push_str(label);
+            }
+        }
+
+        let slice = snippet.slices.first();
+
+        let (line, column) = match slice {
+            Some(slice) => match slice.annotations.first() {
+                Some(annotation) => {
+                    let (line, column) = position(slice.source, annotation.range.0);
+                    (Some(slice.line_start + line), Some(column))
+                }
+                None => (Some(slice.line_start), None),
+            },
+            None => (None, None),
+        };
+
+        Self {
+            file: slice.and_then(|s| s.origin).unwrap_or_default().to_owned(),
+            slug: title.and_then(|t| t.id).unwrap_or_default().to_owned(),
+            level: title.map(|t| t.annotation_type),
+            line,
+            column,
+            message,
+            formatted: DisplayList::from(snippet).to_string(),
+        }
+    }
+}
+
+/// Groups `diagnostics` by file, then by slug, keeping the order they were
+/// reported in within each group.
+pub(super) fn group(
+    diagnostics: &[Diagnostic],
+) -> BTreeMap<&str, BTreeMap<&str, Vec<&Diagnostic>>> {
+    let mut groups: BTreeMap<_, BTreeMap<_, Vec<_>>> = BTreeMap::new();
+
+    for diagnostic in diagnostics {
+        groups
+            .entry(diagnostic.file.as_str())
+            .or_default()
+            .entry(diagnostic.slug.as_str())
+            .or_default()
+            .push(diagnostic);
+    }
+
+    groups
+}
+
+/// Escapes `text` for use in XML character data, dropping characters XML
+/// can't represent at all.
+pub(super) fn escape(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&apos;"),
+            '\t' | '\n' | '\r' => output.push(c),
+            c if c < ' ' => (),
+            c => output.push(c),
+        }
+    }
+
+    output
+}
+
+/// Escapes `text` for use in an XML attribute value, preserving whitespace.
+pub(super) fn escape_attribute(text: &str) -> String {
+    escape(text)
+        .replace('\t', "&#9;")
+        .replace('\n', "&#10;")
+        .replace('\r', "&#13;")
+}
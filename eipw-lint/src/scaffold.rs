@@ -0,0 +1,300 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Generates skeleton proposals with the preamble headers and sections
+//! required by a lint configuration.
+
+use chrono::{Datelike, NaiveDateTime};
+
+use crate::lints::preamble::regex::Mode;
+use crate::lints::DefaultLint;
+
+use eipw_preamble::{OwnedField, OwnedPreamble};
+
+use regex_syntax::hir::{Class, Hir, HirKind};
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Skeleton proposal generator, configured from the `preamble::Required`,
+/// `preamble::Order`, `preamble::RequiredIfEq`, `markdown::SectionRequired`,
+/// and `markdown::SectionOrder` lints.
+///
+/// Headers without a given value get a placeholder chosen to satisfy the
+/// lints checking them, where possible (eg. the first permitted value for
+/// `preamble::OneOf`, today's date for `preamble::Date`, or a string matching
+/// the pattern of a `preamble::Regex` in [`Mode::Includes`].)
+#[derive(Debug, Clone)]
+pub struct Scaffold<S> {
+    lints: Vec<DefaultLint<S>>,
+}
+
+impl<S> Scaffold<S>
+where
+    S: Clone,
+{
+    pub fn from_lints<'l, I>(lints: I) -> Self
+    where
+        I: IntoIterator<Item = &'l DefaultLint<S>>,
+        S: 'l,
+    {
+        Self {
+            lints: lints.into_iter().cloned().collect(),
+        }
+    }
+}
+
+fn today() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+
+    let date = NaiveDateTime::from_timestamp_opt(secs as i64, 0)
+        .unwrap_or_default()
+        .date();
+
+    format!("{:04}-{:02}-{:02}", date.year(), date.month(), date.day())
+}
+
+/// Picks a character matched by `class`, preferring readable ones.
+fn example_char(class: &Class) -> Option<String> {
+    let ranges: Vec<(u32, u32)> = match class {
+        Class::Unicode(c) => c
+            .ranges()
+            .iter()
+            .map(|r| (r.start() as u32, r.end() as u32))
+            .collect(),
+        Class::Bytes(c) => c
+            .ranges()
+            .iter()
+            .map(|r| (r.start() as u32, r.end() as u32))
+            .collect(),
+    };
+
+    let contains = |c: char| ranges.iter().any(|(s, e)| (*s..=*e).contains(&(c as u32)));
+
+    // An unescaped `.` is almost always meant literally (eg. in a domain.)
+    let preferred = match contains('\0') && contains('/') && contains(char::MAX) {
+        true => &['.'][..],
+        false => &['a', '0'][..],
+    };
+
+    preferred
+        .iter()
+        .copied()
+        .find(|c| contains(*c))
+        .or_else(|| ranges.first().and_then(|(s, _)| char::from_u32(*s)))
+        .map(String::from)
+}
+
+/// Builds a short string matched by `hir`, if possible.
+fn example(hir: &Hir) -> Option<String> {
+    match hir.kind() {
+        HirKind::Empty | HirKind::Look(_) => Some(String::new()),
+        HirKind::Literal(l) => String::from_utf8(l.0.to_vec()).ok(),
+        HirKind::Class(c) => example_char(c),
+        HirKind::Repetition(r) => Some(example(&r.sub)?.repeat(r.min as usize)),
+        HirKind::Capture(c) => example(&c.sub),
+        HirKind::Concat(items) => items.iter().map(example).collect(),
+        HirKind::Alternation(items) => items.first().and_then(example),
+    }
+}
+
+impl<S> Scaffold<S>
+where
+    S: AsRef<str>,
+{
+    /// Whether `value` satisfies every `preamble::Regex` checking header
+    /// `name`.
+    fn satisfies_patterns(&self, name: &str, value: &str) -> bool {
+        self.lints.iter().all(|lint| match lint {
+            DefaultLint::PreambleRegex(l) if l.name.as_ref() == name => {
+                match regex::Regex::new(l.pattern.as_ref()) {
+                    Ok(re) => re.is_match(value) == (l.mode == Mode::Includes),
+                    Err(_) => true,
+                }
+            }
+            _ => true,
+        })
+    }
+
+    fn placeholder(&self, name: &str) -> String {
+        let value = self.typed_placeholder(name);
+
+        if self.satisfies_patterns(name, &value) {
+            return value;
+        }
+
+        let candidates = self.lints.iter().filter_map(|lint| match lint {
+            DefaultLint::PreambleRegex(l)
+                if l.name.as_ref() == name && l.mode == Mode::Includes =>
+            {
+                example(&regex_syntax::parse(l.pattern.as_ref()).ok()?)
+            }
+            _ => None,
+        });
+
+        for candidate in candidates {
+            if self.satisfies_patterns(name, &candidate) {
+                return candidate;
+            }
+        }
+
+        value
+    }
+
+    /// Returns the source of a proposal with the given preamble `values`, and
+    /// placeholders for everything else.
+    pub fn generate(&self, values: &[(&str, &str)]) -> String {
+        let values: HashMap<_, _> = values.iter().copied().collect();
+
+        let mut output = String::new();
+        output.push_str("---\n");
+        output.push_str(&self.preamble(&values).to_string());
+        output.push_str("\n---\n");
+
+        for (name, content) in self.sections() {
+            output.push_str("\n## ");
+            output.push_str(&name);
+            output.push_str("\n\n");
+            output.push_str(content.trim());
+            output.push('\n');
+        }
+
+        output
+    }
+
+    fn typed_placeholder(&self, name: &str) -> String {
+        for lint in &self.lints {
+            let value = match lint {
+                DefaultLint::PreambleOneOf(l) if l.name.as_ref() == name => {
+                    l.values.first().map(|v| v.as_ref().to_owned())
+                }
+                DefaultLint::PreambleDate { name: l } if l.0.as_ref() == name => Some(today()),
+                DefaultLint::PreambleUint { name: l } if l.0.as_ref() == name => Some("0".into()),
+                DefaultLint::PreambleUintList { name: l } if l.0.as_ref() == name => {
+                    Some("0".into())
+                }
+                DefaultLint::PreambleAuthor { name: l } if l.0.as_ref() == name => {
+                    Some("FirstName LastName (@GitHubUsername)".into())
+                }
+                DefaultLint::PreambleUrl { name: l } if l.0.as_ref() == name => {
+                    Some("https://example.com/".into())
+                }
+                _ => None,
+            };
+
+            if let Some(value) = value {
+                return value;
+            }
+        }
+
+        format!("Replace with the {}", name)
+    }
+
+    fn preamble(&self, values: &HashMap<&str, &str>) -> OwnedPreamble {
+        let mut preamble = OwnedPreamble::default();
+
+        let mut pending: Vec<String> = values.keys().map(|k| (*k).to_owned()).collect();
+        pending.sort();
+
+        for lint in &self.lints {
+            if let DefaultLint::PreambleRequired { names } = lint {
+                pending.extend(names.0.iter().map(|n| n.as_ref().to_owned()));
+            }
+        }
+
+        // Adding a header (eg. `type`) can require another (eg. `category`),
+        // so repeat until nothing changes.
+        while !pending.is_empty() {
+            for name in pending.drain(..) {
+                if preamble.by_name(&name).is_some() {
+                    continue;
+                }
+
+                let value = match values.get(name.as_str()) {
+                    Some(v) => (*v).to_owned(),
+                    None => self.placeholder(&name),
+                };
+
+                preamble.insert(preamble.len(), OwnedField::new(name, value));
+            }
+
+            for lint in &self.lints {
+                let l = match lint {
+                    DefaultLint::PreambleRequiredIfEq(l) => l,
+                    _ => continue,
+                };
+
+                let matches = preamble
+                    .by_name(l.when.as_ref())
+                    .map_or(false, |f| f.value() == l.equals.as_ref());
+
+                if matches && preamble.by_name(l.then.as_ref()).is_none() {
+                    pending.push(l.then.as_ref().to_owned());
+                }
+            }
+        }
+
+        for lint in &self.lints {
+            if let DefaultLint::PreambleOrder { names } = lint {
+                preamble.reorder(&names.0);
+            }
+        }
+
+        preamble
+    }
+
+    /// Names and contents of the sections to include, in order.
+    fn sections(&self) -> Vec<(String, String)> {
+        let mut required: Vec<String> = vec![];
+        let mut order: Vec<String> = vec![];
+        let mut templates: HashMap<String, String> = HashMap::new();
+
+        for lint in &self.lints {
+            match lint {
+                DefaultLint::MarkdownSectionRequired { sections } => {
+                    required.extend(sections.0.iter().map(|s| s.as_ref().to_owned()))
+                }
+                DefaultLint::MarkdownSectionOrder { sections } => {
+                    order = sections.0.iter().map(|s| s.as_ref().to_owned()).collect()
+                }
+                DefaultLint::MarkdownCopyright(l) => {
+                    required.push(l.section.as_ref().to_owned());
+                    templates.insert(
+                        l.section.as_ref().to_owned(),
+                        l.template.as_ref().to_owned(),
+                    );
+                }
+                _ => (),
+            }
+        }
+
+        let mut sections: Vec<_> = order
+            .iter()
+            .filter(|o| required.contains(o))
+            .cloned()
+            .collect();
+
+        for name in required {
+            if !sections.contains(&name) {
+                sections.push(name);
+            }
+        }
+
+        sections
+            .into_iter()
+            .map(|name| {
+                let content = match templates.remove(&name) {
+                    Some(t) => t,
+                    None => format!("Replace with the contents of the {} section.", name),
+                };
+                (name, content)
+            })
+            .collect()
+    }
+}
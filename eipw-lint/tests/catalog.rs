@@ -0,0 +1,84 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use assert_matches::assert_matches;
+
+use eipw_lint::catalog::{Entry, Error};
+
+use eipw_preamble::SourceError;
+
+#[test]
+fn parse() {
+    let src = "---
+eip: 1234
+title: Hello
+author: A (@a), B <b@example.com>
+status: Draft
+type: Standards Track
+category: ERC
+created: 2023-01-01
+requires: 20, 721
+---
+
+Body.
+";
+
+    assert_eq!(
+        Entry::parse(src).unwrap(),
+        Entry {
+            number: 1234,
+            title: "Hello".into(),
+            status: "Draft".into(),
+            type_: "Standards Track".into(),
+            category: Some("ERC".into()),
+            authors: vec!["A (@a)".into(), "B <b@example.com>".into()],
+            created: "2023-01-01".into(),
+            requires: vec![20, 721],
+        }
+    );
+}
+
+#[test]
+fn optional() {
+    let src = "---\neip: 1\ntitle: x\nstatus: Living\ntype: Meta\ncreated: 2015-10-27\n---\n";
+
+    let entry = Entry::parse(src).unwrap();
+
+    assert_eq!(entry.category, None);
+    assert!(entry.authors.is_empty());
+    assert!(entry.requires.is_empty());
+}
+
+#[test]
+fn errors() {
+    assert_matches!(
+        Entry::parse("no preamble"),
+        Err(Error::Source {
+            source: SourceError::Split { .. }
+        })
+    );
+    assert_matches!(
+        Entry::parse("---\neip: 1\nbroken\n---\n"),
+        Err(Error::Source {
+            source: SourceError::Preamble { line: 3 }
+        })
+    );
+    assert_matches!(
+        Entry::parse("---\neip: 1\ncreated: 2015-10-27\n---\n"),
+        Err(Error::Missing { name: "title" })
+    );
+    assert_matches!(
+        Entry::parse("---\neip: x\ncreated: 2015-10-27\n---\n"),
+        Err(Error::Invalid { name: "eip", .. })
+    );
+    assert_matches!(
+        Entry::parse("---\neip: 1\ncreated: 2015-13-27\n---\n"),
+        Err(Error::Invalid {
+            name: "created",
+            ..
+        })
+    );
+}
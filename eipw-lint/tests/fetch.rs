@@ -0,0 +1,61 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "tokio")]
+
+use eipw_lint::fetch::{DefaultFetch, Recording};
+use eipw_lint::lints::preamble::RequiresStatus;
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[tokio::test]
+async fn recording() {
+    let src = "---\neip: 3\nrequires: 1, 2\nstatus: Draft\n---\n\nHello world.\n";
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!(
+        "eipw-fetch-recording-{}-{}",
+        std::process::id(),
+        nanos
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let path = dir.join("eip-3.md");
+    std::fs::write(&path, src).unwrap();
+
+    let recording = Recording::new(DefaultFetch);
+
+    Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "preamble-requires-status",
+            RequiresStatus {
+                requires: "requires",
+                status: "status",
+                prefix: "eip-",
+                suffix: ".md",
+                flow: vec![vec!["Draft"], vec!["Final"]],
+            },
+        )
+        .set_fetch(recording.clone())
+        .check_file(&path)
+        .run()
+        .await
+        .unwrap();
+
+    let expected: Vec<PathBuf> = vec![dir.join("eip-1.md"), dir.join("eip-2.md"), path];
+    let actual: Vec<_> = recording.paths().into_iter().collect();
+
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(actual, expected);
+}
@@ -0,0 +1,87 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use assert_matches::assert_matches;
+
+use eipw_lint::format::{Error, Formatter};
+use eipw_lint::lints::preamble::{List, Order};
+use eipw_lint::lints::DefaultLint;
+
+fn formatter() -> Formatter<&'static str> {
+    Formatter {
+        order: vec!["eip", "title", "author", "status"],
+        lists: vec!["author", "requires"],
+    }
+}
+
+#[test]
+fn already_formatted() {
+    let src = "---\neip: 1\ntitle: Hello\nauthor: A (@a), B (@b)\n---\n\n## Abstract\n";
+
+    assert_eq!(formatter().format(src).unwrap(), src);
+}
+
+#[test]
+fn preamble() {
+    let src =
+        "---\ntitle:  Hello  \nfoo:bar\neip: 1\nauthor: A (@a),B (@b) ,\nempty:\n---\n\nBody.\n";
+
+    assert_eq!(
+        formatter().format(src).unwrap(),
+        "---\neip: 1\nfoo: bar\ntitle: Hello\nauthor: A (@a), B (@b)\nempty:\n---\n\nBody.\n"
+    );
+}
+
+#[test]
+fn headings() {
+    let src = "---\neip: 1\n---\n\n##Abstract\n\n```\n#notheading\n```\n\n#Spec\n####### Seven\n";
+
+    assert_eq!(
+        formatter().format(src).unwrap(),
+        "---\neip: 1\n---\n\n## Abstract\n\n```\n#notheading\n```\n\n# Spec\n####### Seven\n"
+    );
+}
+
+#[test]
+fn heading_like_paragraph_continuation() {
+    let src = "---\neip: 1\n---\n\nSee the discussion in\n#1234 from before.\n";
+
+    assert_eq!(formatter().format(src).unwrap(), src);
+}
+
+#[test]
+fn heading_like_html_block() {
+    let src = "---\neip: 1\n---\n\n<div>\n#notaheading\n</div>\n";
+
+    assert_eq!(formatter().format(src).unwrap(), src);
+}
+
+#[test]
+fn from_lints() {
+    let lints = [
+        DefaultLint::PreambleOrder {
+            names: Order(vec!["b", "a"]),
+        },
+        DefaultLint::PreambleList { name: List("a") },
+    ];
+
+    let formatter = Formatter::from_lints(&lints);
+
+    assert_eq!(
+        formatter.format("---\na: x,y\nb: z\n---\n").unwrap(),
+        "---\nb: z\na: x, y\n---\n"
+    );
+}
+
+#[test]
+fn invalid_preamble() {
+    assert_matches!(
+        formatter().format("---\neip: 1\nbroken\n---\n"),
+        Err(Error::Preamble { line: 3 })
+    );
+
+    assert_matches!(formatter().format("no preamble"), Err(Error::Split { .. }));
+}
@@ -0,0 +1,120 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::catalog::Entry;
+use eipw_lint::graph::{EdgeKind, Graph};
+
+fn entry(number: u64, status: &str, requires: &[u64]) -> Entry {
+    Entry {
+        number,
+        title: format!("Title {}", number),
+        status: status.into(),
+        type_: "Standards Track".into(),
+        category: Some("Core".into()),
+        authors: vec![],
+        created: "2023-01-01".into(),
+        requires: requires.to_vec(),
+    }
+}
+
+fn graph() -> Graph {
+    let mut graph = Graph::default();
+    graph.insert(&entry(1, "Final", &[]));
+    graph.insert(&entry(2, "Draft", &[1]));
+    graph.insert(&entry(3, "Review", &[2]));
+    graph.insert(&entry(4, "Draft", &[1]));
+    graph
+}
+
+#[test]
+fn dot() {
+    assert_eq!(
+        graph().to_dot(),
+        r#"digraph {
+    1 [label="EIP-1: Title 1", style=filled, fillcolor=palegreen];
+    2 [label="EIP-2: Title 2", style=filled, fillcolor=lightgrey];
+    3 [label="EIP-3: Title 3", style=filled, fillcolor=lightskyblue];
+    4 [label="EIP-4: Title 4", style=filled, fillcolor=lightgrey];
+    2 -> 1;
+    3 -> 2;
+    4 -> 1;
+}
+"#
+    );
+}
+
+#[test]
+fn mermaid() {
+    let mut graph = Graph::default();
+    graph.insert(&entry(1, "Final", &[]));
+    graph.insert(&entry(2, "Draft", &[1]));
+    graph
+        .insert_references(2, "Unlike EIP-3, this uses `EIP-4` and EIP-1.\n")
+        .unwrap();
+
+    assert_eq!(
+        graph.to_mermaid(),
+        r#"flowchart LR
+    n1["EIP-1: Title 1"]
+    n2["EIP-2: Title 2"]
+    n2 --> n1
+    n2 -.-> n3
+    classDef lightgrey fill:lightgrey
+    class n2 lightgrey
+    classDef palegreen fill:palegreen
+    class n1 palegreen
+"#
+    );
+}
+
+#[test]
+fn deps_of() {
+    let deps = graph().deps_of(3);
+
+    let nodes: Vec<_> = deps.nodes().map(|n| n.number).collect();
+    let edges: Vec<_> = deps.edges().map(|e| (e.from, e.to)).collect();
+
+    assert_eq!(nodes, [1, 2, 3]);
+    assert_eq!(edges, [(2, 1), (3, 2)]);
+}
+
+#[test]
+fn dependents_of() {
+    let dependents = graph().dependents_of(1);
+
+    let nodes: Vec<_> = dependents.nodes().map(|n| n.number).collect();
+
+    assert_eq!(nodes, [1, 2, 3, 4]);
+    assert!(dependents.edges().all(|e| e.kind == EdgeKind::Requires));
+}
+
+#[test]
+fn json() {
+    let mut graph = Graph::default();
+    graph.insert(&entry(2, "Draft", &[1]));
+
+    assert_eq!(
+        serde_json::to_string(&graph).unwrap(),
+        concat!(
+            r#"{"nodes":[{"number":2,"title":"Title 2","status":"Draft","category":"Core"}],"#,
+            r#""edges":[{"from":2,"to":1,"kind":"requires"}]}"#,
+        )
+    );
+}
+
+#[test]
+fn references_use_lint_extensions() {
+    let mut graph = Graph::default();
+
+    // With footnotes enabled (like the lints), `[^eip-5]` is a footnote
+    // label rather than text mentioning EIP-5.
+    graph
+        .insert_references(2, "See EIP-3.[^eip-5]\n\n[^eip-5]: Footnote.\n")
+        .unwrap();
+
+    let edges: Vec<_> = graph.edges().map(|e| e.to).collect();
+    assert_eq!(edges, [3]);
+}
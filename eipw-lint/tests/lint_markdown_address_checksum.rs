@@ -0,0 +1,156 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::fix;
+use eipw_lint::lints::markdown::AddressChecksum;
+use eipw_lint::reporters::{Fixes, Text};
+use eipw_lint::Linter;
+
+#[tokio::test]
+async fn valid() {
+    let src = r#"---
+header: value1
+---
+
+Deployed at 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed and `0x0000000000000000000000000000000000000001`.
+
+```solidity
+address constant OWNER = 0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359;
+```
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-address-checksum", AddressChecksum)
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn lowercase_in_text() {
+    let src = r#"---
+header: value1
+---
+
+Deployed at 0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-address-checksum", AddressChecksum)
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-address-checksum]: address is not EIP-55 checksummed
+  |
+5 | Deployed at 0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed.
+  |             ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+  |
+  = help: use `0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed`
+"#
+    );
+}
+
+#[tokio::test]
+async fn invalid_in_inline_code() {
+    let src = r#"---
+header: value1
+---
+
+Deployed at `0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD`.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-address-checksum", AddressChecksum)
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-address-checksum]: address has an invalid EIP-55 checksum
+  |
+5 | Deployed at `0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD`.
+  |              ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+  |
+  = help: use `0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed`
+"#
+    );
+}
+
+#[tokio::test]
+async fn invalid_in_code_block() {
+    let src = r#"---
+header: value1
+---
+
+```solidity
+address constant A = 0xFB6916095CA1DF60BB79CE92CE3EA74C37C5D359;
+address constant B = 0xFB6916095CA1DF60BB79CE92CE3EA74C37C5D359;
+```
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-address-checksum", AddressChecksum)
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-address-checksum]: address is not EIP-55 checksummed
+  |
+6 | address constant A = 0xFB6916095CA1DF60BB79CE92CE3EA74C37C5D359;
+  |                      ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+  |
+  = help: use `0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359`
+error[markdown-address-checksum]: address is not EIP-55 checksummed
+  |
+7 | address constant B = 0xFB6916095CA1DF60BB79CE92CE3EA74C37C5D359;
+  |                      ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+  |
+  = help: use `0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359`
+"#
+    );
+}
+
+#[tokio::test]
+async fn apply_fixes() {
+    let src = "---\nheader: value1\n---\n\nSee 0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed and\n`0xFB6916095CA1DF60BB79CE92CE3EA74C37C5D359`.\n";
+
+    let reporter = Linter::new(Fixes::new(Text::<String>::default()))
+        .clear_lints()
+        .deny("markdown-address-checksum", AddressChecksum)
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap();
+
+    let (_, fixes) = reporter.into_parts();
+    let (fixed, skipped) = fix::apply(src, &fixes);
+
+    assert!(skipped.is_empty());
+    assert_eq!(
+        fixed,
+        "---\nheader: value1\n---\n\nSee 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed and\n`0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359`.\n"
+    );
+}
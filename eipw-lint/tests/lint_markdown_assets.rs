@@ -0,0 +1,401 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::fetch::{Entry, Fetch};
+use eipw_lint::lints::markdown::{AssetsForeign, AssetsNumber, AssetsSize, AssetsUnused};
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+
+#[derive(Debug, Default, Clone)]
+struct Memory {
+    files: HashMap<PathBuf, String>,
+    directories: HashMap<PathBuf, Vec<Entry>>,
+}
+
+impl Memory {
+    fn file(mut self, path: &str, content: &str) -> Self {
+        self.files.insert(path.into(), content.into());
+        self
+    }
+
+    fn directory(mut self, path: &str, entries: &[(&str, u64)]) -> Self {
+        let entries = entries
+            .iter()
+            .map(|(p, len)| Entry {
+                path: p.into(),
+                len: *len,
+            })
+            .collect();
+        self.directories.insert(path.into(), entries);
+        self
+    }
+}
+
+impl Fetch for Memory {
+    fn fetch(
+        &self,
+        path: PathBuf,
+    ) -> Pin<Box<dyn Future<Output = Result<String, std::io::Error>>>> {
+        let result = self
+            .files
+            .get(&path)
+            .cloned()
+            .ok_or_else(|| std::io::ErrorKind::NotFound.into());
+        Box::pin(async move { result })
+    }
+
+    fn list(
+        &self,
+        path: PathBuf,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Entry>, std::io::Error>>>> {
+        let result = self
+            .directories
+            .get(&path)
+            .cloned()
+            .ok_or_else(|| std::io::ErrorKind::NotFound.into());
+        Box::pin(async move { result })
+    }
+}
+
+const OTHER: &str = r#"---
+eip: 2
+---
+hello
+"#;
+
+#[tokio::test]
+async fn foreign() {
+    let src = r#"---
+eip: 1
+---
+
+![diagram](../assets/eip-2/diagram.png)
+"#;
+
+    let fetch = Memory::default()
+        .file("EIPS/eip-1.md", src)
+        .file("EIPS/eip-2.md", OTHER);
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-assets-foreign",
+            AssetsForeign {
+                name: "eip",
+                assets: "../assets",
+                prefix: "eip-",
+                suffix: ".md",
+            },
+        )
+        .set_fetch(fetch)
+        .check_file(Path::new("EIPS/eip-1.md"))
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-assets-foreign]: asset `../assets/eip-2/diagram.png` belongs to another proposal (`eip-2.md`)
+ --> EIPS/eip-1.md
+  |
+5 | ![diagram](../assets/eip-2/diagram.png)
+  |
+  = help: copy the file into `../assets/eip-1`
+"#
+    );
+}
+
+#[tokio::test]
+async fn foreign_own_directory() {
+    let src = r#"---
+eip: 1
+---
+
+![diagram](./../assets/eip-1/diagram.png) and <img src="../assets/eip-1/b.png">
+"#;
+
+    let fetch = Memory::default().file("EIPS/eip-1.md", src);
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-assets-foreign",
+            AssetsForeign {
+                name: "eip",
+                assets: "../assets",
+                prefix: "eip-",
+                suffix: ".md",
+            },
+        )
+        .deny(
+            "markdown-assets-number",
+            AssetsNumber {
+                name: "eip",
+                assets: "../assets",
+                prefix: "eip-",
+                suffix: ".md",
+            },
+        )
+        .set_fetch(fetch)
+        .check_file(Path::new("EIPS/eip-1.md"))
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn number() {
+    let src = r#"---
+eip: 1
+---
+
+![diagram](../assets/eip-2/diagram.png)
+
+[draft](../assets/eip-draft_thing/spec.pdf)
+"#;
+
+    let fetch = Memory::default()
+        .file("EIPS/eip-1.md", src)
+        .file("EIPS/eip-2.md", OTHER);
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-assets-number",
+            AssetsNumber {
+                name: "eip",
+                assets: "../assets",
+                prefix: "eip-",
+                suffix: ".md",
+            },
+        )
+        .set_fetch(fetch)
+        .check_file(Path::new("EIPS/eip-1.md"))
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-assets-number]: asset directory `eip-draft_thing` does not match the preamble header `eip`
+ --> EIPS/eip-1.md
+  |
+7 | [draft](../assets/eip-draft_thing/spec.pdf)
+  |
+  = help: assets for this proposal belong in `../assets/eip-1`
+"#
+    );
+}
+
+#[tokio::test]
+async fn unused() {
+    let src = r#"---
+eip: 1
+---
+
+![diagram](../assets/eip-1/diagram.png)
+"#;
+
+    let fetch = Memory::default().file("EIPS/eip-1.md", src).directory(
+        "EIPS/../assets/eip-1",
+        &[("diagram.png", 10), ("old/diagram.png", 10)],
+    );
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-assets-unused",
+            AssetsUnused {
+                name: "eip",
+                assets: "../assets",
+                prefix: "eip-",
+            },
+        )
+        .set_fetch(fetch)
+        .check_file(Path::new("EIPS/eip-1.md"))
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-assets-unused]: asset(s) in `../assets/eip-1` are never referenced: `old/diagram.png`
+ --> EIPS/eip-1.md
+  |
+2 | eip: 1
+  |
+  = help: link to each asset from the body, or remove it
+"#
+    );
+}
+
+#[tokio::test]
+async fn unused_missing_directory() {
+    let src = r#"---
+eip: 1
+---
+
+Hello world!
+"#;
+
+    let fetch = Memory::default().file("EIPS/eip-1.md", src);
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-assets-unused",
+            AssetsUnused {
+                name: "eip",
+                assets: "../assets",
+                prefix: "eip-",
+            },
+        )
+        .set_fetch(fetch)
+        .check_file(Path::new("EIPS/eip-1.md"))
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn size() {
+    let src = r#"---
+eip: 1
+---
+
+Hello world!
+"#;
+
+    let fetch = Memory::default().file("EIPS/eip-1.md", src).directory(
+        "EIPS/../assets/eip-1",
+        &[("small.png", 10), ("large.PNG", 2000), ("large.md", 2000)],
+    );
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-assets-size",
+            AssetsSize {
+                name: "eip",
+                assets: "../assets",
+                prefix: "eip-",
+                max: 1000,
+                exempt: vec!["md"],
+            },
+        )
+        .set_fetch(fetch)
+        .check_file(Path::new("EIPS/eip-1.md"))
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-assets-size]: asset `../assets/eip-1/large.PNG` is too large (2000 bytes)
+ --> EIPS/eip-1.md
+  |
+2 | eip: 1
+  |
+  = help: files may be at most 1000 bytes
+"#
+    );
+}
+
+#[tokio::test]
+async fn string_sources() {
+    let src = r#"---
+eip: 1
+---
+
+Hello world!
+"#;
+
+    for origin in [None, Some("EIPS/eip-1.md")] {
+        let reports = Linter::<Text<String>>::default()
+            .clear_lints()
+            .deny(
+                "markdown-assets-unused",
+                AssetsUnused {
+                    name: "eip",
+                    assets: "../assets",
+                    prefix: "eip-",
+                },
+            )
+            .deny(
+                "markdown-assets-size",
+                AssetsSize {
+                    name: "eip",
+                    assets: "../assets",
+                    prefix: "eip-",
+                    max: 1000,
+                    exempt: vec![],
+                },
+            )
+            .check_slice(origin, src)
+            .run()
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(reports, "");
+    }
+}
+
+#[tokio::test]
+async fn list_unsupported() {
+    let src = r#"---
+eip: 1
+---
+
+Hello world!
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-assets-unused",
+            AssetsUnused {
+                name: "eip",
+                assets: "../assets",
+                prefix: "eip-",
+            },
+        )
+        .set_fetch(Files(Memory::default().file("EIPS/eip-1.md", src)))
+        .check_file(Path::new("EIPS/eip-1.md"))
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+/// Fetches files, but can't list directories.
+struct Files(Memory);
+
+impl Fetch for Files {
+    fn fetch(
+        &self,
+        path: PathBuf,
+    ) -> Pin<Box<dyn Future<Output = Result<String, std::io::Error>>>> {
+        self.0.fetch(path)
+    }
+}
@@ -0,0 +1,245 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::fix;
+use eipw_lint::lints::markdown::characters::FieldCharacters;
+use eipw_lint::lints::markdown::Characters;
+use eipw_lint::reporters::{Fixes, Text};
+use eipw_lint::Linter;
+
+fn lint() -> Characters<&'static str> {
+    Characters {
+        allow: vec![],
+        fields: vec![],
+    }
+}
+
+#[tokio::test]
+async fn valid() {
+    let src = "---\nauthor: José (@jose)\ntitle: Plain title\n---\n\nLet α be the Привет value, with “quotes” in prose.\n";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-characters", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn invisible() {
+    let src = "---\nheader: value1\n---\n\nHello\u{200b}world and \u{202e}evil.\n";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-characters", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-characters]: invisible character U+200B
+  |
+5 | Hello<U+200B>world and <U+202E>evil.
+  |      ^^^^^^^^
+  |
+  = help: remove it
+error[markdown-characters]: bidirectional control character U+202E
+  |
+5 | Hello<U+200B>world and <U+202E>evil.
+  |                        ^^^^^^^^
+  |
+  = help: remove it
+"#
+    );
+}
+
+#[tokio::test]
+async fn confusable() {
+    let src = "---\ntitle: Тoken standard\n---\n\nThe Ethereum paсkage for ＥＩＰ.\n";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-characters", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-characters]: `Т` (U+0422) looks like `T`
+  |
+2 | title: Тoken standard
+  |        ^
+  |
+  = help: use `T` instead
+error[markdown-characters]: `с` (U+0441) looks like `c`
+  |
+5 | The Ethereum paсkage for ＥＩＰ.
+  |                ^
+  |
+  = help: use `c` instead
+error[markdown-characters]: `Ｅ` (U+FF25) looks like `E`
+  |
+5 | The Ethereum paсkage for ＥＩＰ.
+  |                          ^^
+  |
+  = help: use `E` instead
+error[markdown-characters]: `Ｉ` (U+FF29) looks like `I`
+  |
+5 | The Ethereum paсkage for ＥＩＰ.
+  |                            ^^
+  |
+  = help: use `I` instead
+error[markdown-characters]: `Ｐ` (U+FF30) looks like `P`
+  |
+5 | The Ethereum paсkage for ＥＩＰ.
+  |                              ^^
+  |
+  = help: use `P` instead
+"#
+    );
+}
+
+#[tokio::test]
+async fn code_blocks() {
+    let src = "---\nheader: value1\n---\n\n```solidity\nstring name = \"Сoin\";\nuint x; // \u{202e}evil\n```\n";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-characters", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-characters]: bidirectional control character U+202E
+  |
+7 | uint x; // <U+202E>evil
+  |            ^^^^^^^^
+  |
+  = help: remove it
+"#
+    );
+}
+
+#[tokio::test]
+async fn non_breaking_space() {
+    let src = "---\ntitle: Plain\u{a0}title\n---\n\nHello.\n";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-characters", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn typographic() {
+    let src = "---\ntitle: Don’t panic\ndescription: Old — new\n---\n\nDon’t panic.\n";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-characters", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-characters]: typographic character `’` (U+2019) in preamble header `title`
+  |
+2 | title: Don’t panic
+  |           ^
+  |
+  = help: use `'` instead
+error[markdown-characters]: typographic character `—` (U+2014) in preamble header `description`
+  |
+3 | description: Old — new
+  |                  ^
+  |
+  = help: use `-` instead
+"#
+    );
+}
+
+#[tokio::test]
+async fn fields() {
+    let src = "---\ntitle: Café standard\ndescription: Old — new…\n---\n\nHello.\n";
+
+    let lint = Characters {
+        allow: vec!["…"],
+        fields: vec![
+            FieldCharacters {
+                name: "title",
+                ascii: true,
+                allow: vec![],
+            },
+            FieldCharacters {
+                name: "description",
+                ascii: false,
+                allow: vec!["—"],
+            },
+        ],
+    };
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-characters", lint)
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-characters]: non-ASCII character `é` (U+00E9) in preamble header `title`
+  |
+2 | title: Café standard
+  |           ^
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn apply_fixes() {
+    let src = "---\ntitle: Don’t use Тokens\n---\n\nZero\u{200b}width.\n";
+
+    let reporter = Linter::new(Fixes::new(Text::<String>::default()))
+        .clear_lints()
+        .deny("markdown-characters", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap();
+
+    let (_, fixes) = reporter.into_parts();
+    let (fixed, skipped) = fix::apply(src, &fixes);
+
+    assert!(skipped.is_empty());
+    assert_eq!(fixed, "---\ntitle: Don't use Tokens\n---\n\nZerowidth.\n");
+}
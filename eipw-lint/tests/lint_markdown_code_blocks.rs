@@ -0,0 +1,319 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::markdown::{CodeBlockLanguage, CodeBlockSyntax};
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+
+fn syntax() -> CodeBlockSyntax<&'static str> {
+    CodeBlockSyntax {
+        json: vec!["json", "csl-json"],
+        toml: vec!["toml"],
+        yaml: vec!["yaml"],
+    }
+}
+
+#[tokio::test]
+async fn language_missing() {
+    let src = r#"---
+header: value1
+---
+
+```
+hello
+```
+
+    indented blocks are fine
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-code-block-language",
+            CodeBlockLanguage {
+                allowed: vec!["solidity", "python"],
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-code-block-language]: code block is missing a language
+  |
+5 | ```
+  |
+  = help: use one of: `solidity`, `python`
+"#
+    );
+}
+
+#[tokio::test]
+async fn language_not_allowed() {
+    let src = r#"---
+header: value1
+---
+
+```solidity
+contract A {}
+```
+
+```js title="example"
+let a = 1;
+```
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-code-block-language",
+            CodeBlockLanguage {
+                allowed: vec!["solidity", "python"],
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-code-block-language]: code block language `js` is not allowed
+  |
+9 | ```js title="example"
+  |
+  = help: use one of: `solidity`, `python`
+"#
+    );
+}
+
+#[tokio::test]
+async fn syntax_valid() {
+    let src = r#"---
+header: value1
+---
+
+```json
+{"a": [1, 2]}
+```
+
+```toml
+[table]
+key = "value"
+```
+
+```yaml
+key:
+  - value
+```
+
+```yaml
+a: 1
+---
+b: 2
+```
+
+```python
+this isn't checked {
+```
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-code-block-syntax", syntax())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn syntax_json() {
+    let src = r#"---
+header: value1
+---
+
+```csl-json
+{
+    "a": 1,
+    "b": 2,
+}
+```
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-code-block-syntax", syntax())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-code-block-syntax]: code block contains invalid JSON
+  |
+9 | }
+  | ^ trailing comma
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn syntax_toml() {
+    let src = r#"---
+header: value1
+---
+
+- A list item with a block:
+
+  ```toml
+  [table]
+  key = value
+  ```
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-code-block-syntax", syntax())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-code-block-syntax]: code block contains invalid TOML
+  |
+9 |   key = value
+  |         ^ invalid string; expected `"`, `'`
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn syntax_yaml() {
+    let src = r#"---
+header: value1
+---
+
+```yaml
+key: value
+  other: value
+```
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-code-block-syntax", syntax())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-code-block-syntax]: code block contains invalid YAML
+  |
+7 |   other: value
+  |        ^ mapping values are not allowed in this context
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn syntax_empty_line() {
+    let src = "---\nheader: value1\n---\n\n```json\n\n```\n";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-code-block-syntax", syntax())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        "error[markdown-code-block-syntax]: code block contains invalid JSON
+ |
+ |
+ = note: EOF while parsing a value
+"
+    );
+}
+
+#[tokio::test]
+async fn syntax_unterminated() {
+    let src = "---\nheader: value1\n---\n\n```json\n";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-code-block-syntax", syntax())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        "error[markdown-code-block-syntax]: code block contains invalid JSON
+  |
+5 | ```json
+  | ^ EOF while parsing a value
+  |
+"
+    );
+}
+
+#[tokio::test]
+async fn syntax_yaml_second_document() {
+    let src = r#"---
+header: value1
+---
+
+```yaml
+a: 1
+---
+key: value
+  other: value
+```
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-code-block-syntax", syntax())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-code-block-syntax]: code block contains invalid YAML
+  |
+9 |   other: value
+  |        ^ mapping values are not allowed in this context
+  |
+"#
+    );
+}
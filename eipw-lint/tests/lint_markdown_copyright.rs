@@ -0,0 +1,209 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::markdown::Copyright;
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+
+fn lint() -> Copyright<&'static str> {
+    Copyright {
+        section: "Copyright",
+        template: "Copyright and related rights waived via [{license}](../LICENSE.md).",
+        license: "../LICENSE.md",
+    }
+}
+
+#[tokio::test]
+async fn valid() {
+    let src = r#"---
+header: value1
+---
+
+## Copyright
+
+Copyright and related rights waived via [CC0](../LICENSE.md).
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-copyright", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn valid_after_long_preamble() {
+    // The section ends where its last paragraph ends, which depends on end
+    // lines being offset by the preamble like start lines.
+    let src = r#"---
+a: 1
+b: 2
+c: 3
+d: 4
+e: 5
+---
+
+## Copyright
+
+Copyright and related rights waived via [CC0](../LICENSE.md).
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-copyright", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn missing_section() {
+    let src = r#"---
+header: value1
+---
+
+## Specification
+
+Hello world.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-copyright", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn extra_text() {
+    let src = r#"---
+header: value1
+---
+
+## Copyright
+
+Copyright and related rights waived via [CC0](../LICENSE.md).
+
+Also, portions are MIT licensed.
+
+## Appendix
+
+Hello.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-copyright", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-copyright]: the `Copyright` section does not match the template
+  |
+5 | ## Copyright
+6 | 
+7 | Copyright and related rights waived via [CC0](../LICENSE.md).
+8 | 
+9 | Also, portions are MIT licensed.
+  | ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ not in the template
+  |
+  = help: differences from the template:
+  = info: - Also, portions are MIT licensed.
+"#
+    );
+}
+
+#[tokio::test]
+async fn wrong_text() {
+    let src = r#"---
+header: value1
+---
+
+## Copyright
+
+Copyright and related rights waived via [CC0](https://creativecommons.org/publicdomain/zero/1.0/).
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-copyright", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-copyright]: the `Copyright` section must link to `../LICENSE.md`
+  |
+5 | ## Copyright
+  |
+error[markdown-copyright]: the `Copyright` section does not match the template
+  |
+5 | ## Copyright
+6 | 
+7 | Copyright and related rights waived via [CC0](https://creativecommons.org/publicdomain/zero/1.0/).
+  | ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ not in the template
+  |
+  = help: differences from the template:
+  = info: - Copyright and related rights waived via [CC0](https://creativecommons.org/publicdomain/zero/1.0/).
+  = info: + Copyright and related rights waived via [{license}](../LICENSE.md).
+"#
+    );
+}
+
+#[tokio::test]
+async fn empty_section() {
+    let src = r#"---
+header: value1
+---
+
+## Copyright
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-copyright", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-copyright]: the `Copyright` section must link to `../LICENSE.md`
+  |
+5 | ## Copyright
+  |
+error[markdown-copyright]: the `Copyright` section does not match the template
+  |
+5 | ## Copyright
+  |
+  = help: differences from the template:
+  = info: + Copyright and related rights waived via [{license}](../LICENSE.md).
+"#
+    );
+}
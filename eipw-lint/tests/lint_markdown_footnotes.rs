@@ -0,0 +1,159 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::markdown::Footnotes;
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+
+#[tokio::test]
+async fn valid() {
+    let src = r#"---
+header: value1
+---
+
+## Specification
+
+See the paper[^paper], but not `[^code]`.
+
+```
+[^block]
+```
+
+## References
+
+[^paper]: A paper.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-footnotes",
+            Footnotes {
+                section: Some("References"),
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn undefined_and_unused() {
+    let src = r#"---
+header: value1
+---
+
+See the paper[^paper].
+
+[^other]: Another paper.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-footnotes", Footnotes::<&str> { section: None })
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-footnotes]: footnote `[^paper]` is not defined
+  |
+5 | See the paper[^paper].
+  |              ^^^^^^^^
+  |
+error[markdown-footnotes]: footnote `[^other]` is never referenced
+  |
+7 | [^other]: Another paper.
+  | ^^^^^^^^
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn duplicate() {
+    let src = r#"---
+header: value1
+---
+
+See the paper[^paper].
+
+[^paper]: A paper.
+[^Paper]: The same paper.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-footnotes", Footnotes::<&str> { section: None })
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-footnotes]: footnote `[^Paper]` is defined more than once
+  |
+7 | [^paper]: A paper.
+  | ^^^^^^^^ first defined here
+  |
+8 | [^Paper]: The same paper.
+  | ^^^^^^^^ redefined here
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn outside_section() {
+    let src = r#"---
+header: value1
+---
+
+## Specification
+
+See the paper[^paper].
+
+[^paper]: A paper.
+
+## References
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-footnotes",
+            Footnotes {
+                section: Some("References"),
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-footnotes]: footnote `[^paper]` is defined outside the `References` section
+  |
+7 | See the paper[^paper].
+  |              ^^^^^^^^ referenced here
+  |
+9 | [^paper]: A paper.
+  | ^^^^^^^^ defined here
+  |
+"#
+    );
+}
@@ -0,0 +1,127 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::markdown::html_allow::{AllowedTag, HtmlAllow};
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+
+fn lint() -> HtmlAllow<&'static str> {
+    HtmlAllow {
+        tags: vec![
+            AllowedTag {
+                name: "sub",
+                attributes: vec![],
+            },
+            AllowedTag {
+                name: "div",
+                attributes: vec!["align"],
+            },
+            AllowedTag {
+                name: "table",
+                attributes: vec![],
+            },
+            AllowedTag {
+                name: "tr",
+                attributes: vec![],
+            },
+            AllowedTag {
+                name: "td",
+                attributes: vec![],
+            },
+        ],
+    }
+}
+
+#[tokio::test]
+async fn allowed() {
+    let src = r#"---
+header: value1
+---
+
+H<sub>2</sub>O and <sub>x</sub>.
+
+<div align="center">
+<table><tr><td>1</td></tr></table>
+</div>
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-html-allow", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn disallowed_inline() {
+    let src = r#"---
+header: value1
+---
+
+Some <sub>small</sub> and <b>bold</b> text.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-html-allow", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-html-allow]: HTML element `<b>` is not allowed
+  |
+5 | Some <sub>small</sub> and <b>bold</b> text.
+  |                           ^^
+  |
+  = help: use `**text**` instead
+"#
+    );
+}
+
+#[tokio::test]
+async fn disallowed_block() {
+    let src = r#"---
+header: value1
+---
+
+<div style="color: red">
+<iframe src="https://example.com/"></iframe>
+</div>
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-html-allow", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-html-allow]: HTML attribute `style` is not allowed on `<div>`
+  |
+5 | <div style="color: red">
+  |      ^^^^^
+  |
+error[markdown-html-allow]: HTML element `<iframe>` is not allowed
+  |
+6 | <iframe src="https://example.com/"></iframe>
+  | ^^^^^^^
+  |
+"#
+    );
+}
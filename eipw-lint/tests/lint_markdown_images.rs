@@ -0,0 +1,206 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::fetch::{Entry, Fetch};
+use eipw_lint::lints::markdown::Images;
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+
+#[derive(Debug, Default, Clone)]
+struct Memory {
+    files: HashMap<PathBuf, String>,
+    directories: HashMap<PathBuf, Vec<Entry>>,
+}
+
+impl Fetch for Memory {
+    fn fetch(
+        &self,
+        path: PathBuf,
+    ) -> Pin<Box<dyn Future<Output = Result<String, std::io::Error>>>> {
+        let result = self
+            .files
+            .get(&path)
+            .cloned()
+            .ok_or_else(|| std::io::ErrorKind::NotFound.into());
+        Box::pin(async move { result })
+    }
+
+    fn list(
+        &self,
+        path: PathBuf,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Entry>, std::io::Error>>>> {
+        let result = self
+            .directories
+            .get(&path)
+            .cloned()
+            .ok_or_else(|| std::io::ErrorKind::NotFound.into());
+        Box::pin(async move { result })
+    }
+}
+
+fn lint(max: Option<u64>) -> Images<&'static str> {
+    Images {
+        name: "eip",
+        assets: "../assets",
+        prefix: "eip-",
+        formats: vec!["png", "svg"],
+        max,
+    }
+}
+
+#[tokio::test]
+async fn valid() {
+    let src = r#"---
+eip: 1
+---
+
+![a diagram](../assets/eip-1/diagram.png)
+
+<img src="../assets/eip-1/logo.SVG" alt="the logo">
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-images", lint(None))
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn missing_alt() {
+    let src = r#"---
+eip: 1
+---
+
+![](../assets/eip-1/diagram.png)
+
+<img src="../assets/eip-1/logo.svg">
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-images", lint(None))
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-images]: image is missing alt text
+  |
+5 | ![](../assets/eip-1/diagram.png)
+  |
+  = help: describe the image between the brackets, like `![diagram](...)`
+error[markdown-images]: image is missing alt text
+  |
+7 | <img src="../assets/eip-1/logo.svg">
+  |
+  = help: add an `alt` attribute describing the image
+"#
+    );
+}
+
+#[tokio::test]
+async fn location_and_format() {
+    let src = r#"---
+eip: 1
+---
+
+![a diagram](../assets/eip-2/diagram.bmp)
+
+![a photo](https://example.com/photo.png)
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-images", lint(None))
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-images]: image format `bmp` is not supported
+  |
+5 | ![a diagram](../assets/eip-2/diagram.bmp)
+  |
+  = help: use one of: `png`, `svg`
+error[markdown-images]: image `../assets/eip-2/diagram.bmp` is not stored in `../assets/eip-1/`
+  |
+5 | ![a diagram](../assets/eip-2/diagram.bmp)
+  |
+  = help: move the image into `../assets/eip-1/` and link to it with a relative path
+error[markdown-images]: image `https://example.com/photo.png` is not stored in `../assets/eip-1/`
+  |
+7 | ![a photo](https://example.com/photo.png)
+  |
+  = help: move the image into `../assets/eip-1/` and link to it with a relative path
+"#
+    );
+}
+
+#[tokio::test]
+async fn too_large() {
+    let src = r#"---
+eip: 1
+---
+
+![a diagram](../assets/eip-1/diagram.png)
+
+![another diagram](../assets/eip-1/small.png)
+"#;
+
+    let mut fetch = Memory::default();
+    fetch.files.insert("EIPS/eip-1.md".into(), src.into());
+    fetch.directories.insert(
+        "EIPS/../assets/eip-1".into(),
+        vec![
+            Entry {
+                path: "diagram.png".into(),
+                len: 2048,
+            },
+            Entry {
+                path: "small.png".into(),
+                len: 10,
+            },
+        ],
+    );
+
+    let reports = Linter::<Text<String>>::default()
+        .set_fetch(fetch)
+        .clear_lints()
+        .deny("markdown-images", lint(Some(1024)))
+        .check_file(Path::new("EIPS/eip-1.md"))
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-images]: image `../assets/eip-1/diagram.png` is too large (2048 bytes)
+ --> EIPS/eip-1.md
+  |
+5 | ![a diagram](../assets/eip-1/diagram.png)
+  |
+  = help: images may be at most 1024 bytes
+"#
+    );
+}
@@ -0,0 +1,209 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::markdown::InterfaceId;
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+
+#[tokio::test]
+async fn correct() {
+    let src = r#"---
+header: value1
+---
+
+## Specification
+
+```solidity
+/// Note: the ERC-165 identifier for this interface is 0x01ffc9a7.
+interface ERC165 {
+    function supportsInterface(bytes4 interfaceID) external view returns (bool);
+}
+```
+
+The interface identifier of `ERC165` is `0x01ffc9a7`.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-interface-id",
+            InterfaceId {
+                language: "solidity",
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn wrong_in_comment() {
+    let src = r#"---
+header: value1
+---
+
+## Specification
+
+```solidity
+/// Note: the ERC-165 identifier for this interface is 0x01ffc9a8.
+interface ERC165 {
+    function supportsInterface(bytes4 interfaceID) external view returns (bool);
+}
+```
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-interface-id",
+            InterfaceId {
+                language: "solidity",
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-interface-id]: interface ID of `ERC165` is `0x01ffc9a7`, not `0x01ffc9a8`
+  |
+8 | /// Note: the ERC-165 identifier for this interface is 0x01ffc9a8.
+  |                                                        ^^^^^^^^^^
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn wrong_in_prose() {
+    let src = r#"---
+header: value1
+---
+
+## Specification
+
+```solidity
+struct Point {
+    uint x;
+    uint y;
+}
+
+interface IShape {
+    function area(Point[] calldata points) external view returns (uint256);
+    function name() external view returns (string memory);
+}
+```
+
+The interface identifier of `IShape` is `0x12345678`.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-interface-id",
+            InterfaceId {
+                language: "solidity",
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-interface-id]: interface ID of `IShape` is `0x7179552e`, not `0x12345678`
+   |
+19 | The interface identifier of `IShape` is `0x12345678`.
+   |                                          ^^^^^^^^^^
+   |
+"#
+    );
+}
+
+#[tokio::test]
+async fn wrong_selector() {
+    let src = r#"---
+header: value1
+---
+
+## Specification
+
+```solidity
+interface ERC165 {
+    function supportsInterface(bytes4 interfaceID) external view returns (bool);
+}
+```
+
+Calls to `supportsInterface(bytes4)` use the selector `0xdeadbeef`.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-interface-id",
+            InterfaceId {
+                language: "solidity",
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-interface-id]: selector of `supportsInterface(bytes4)` is `0x01ffc9a7`, not `0xdeadbeef`
+   |
+13 | Calls to `supportsInterface(bytes4)` use the selector `0xdeadbeef`.
+   |                                                        ^^^^^^^^^^
+   |
+"#
+    );
+}
+
+#[tokio::test]
+async fn unrelated_hex() {
+    let src = r#"---
+header: value1
+---
+
+## Specification
+
+```solidity
+interface ERC165 {
+    function supportsInterface(bytes4 interfaceID) external view returns (bool);
+}
+```
+
+The magic value is `0xdeadbeef`.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-interface-id",
+            InterfaceId {
+                language: "solidity",
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
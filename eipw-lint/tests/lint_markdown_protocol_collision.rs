@@ -0,0 +1,206 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::fetch::{Entry, Fetch};
+use eipw_lint::lints::markdown::protocol_collision::{Claim, ProtocolCollision};
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+
+#[derive(Debug, Default, Clone)]
+struct Memory {
+    files: HashMap<PathBuf, String>,
+}
+
+impl Memory {
+    fn file(mut self, path: &str, content: &str) -> Self {
+        self.files.insert(path.into(), content.into());
+        self
+    }
+}
+
+impl Fetch for Memory {
+    fn fetch(
+        &self,
+        path: PathBuf,
+    ) -> Pin<Box<dyn Future<Output = Result<String, std::io::Error>>>> {
+        let result = self
+            .files
+            .get(&path)
+            .cloned()
+            .ok_or_else(|| std::io::ErrorKind::NotFound.into());
+        Box::pin(async move { result })
+    }
+
+    fn list(
+        &self,
+        path: PathBuf,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Entry>, std::io::Error>>>> {
+        let entries = self
+            .files
+            .iter()
+            .filter_map(|(p, c)| {
+                Some(Entry {
+                    path: p.strip_prefix(&path).ok()?.to_path_buf(),
+                    len: c.len() as u64,
+                })
+            })
+            .collect();
+        Box::pin(async move { Ok(entries) })
+    }
+}
+
+fn lint() -> ProtocolCollision<&'static str> {
+    ProtocolCollision {
+        proposals: ".",
+        category: "Core",
+        ignore_status: vec!["Withdrawn"],
+        claims: vec![
+            Claim {
+                kind: "opcode",
+                column: Some("^(?i)opcode$"),
+                heading: None,
+                value: "0x[0-9a-fA-F]{2}",
+            },
+            Claim {
+                kind: "transaction type",
+                column: None,
+                heading: Some("(?i)transaction type"),
+                value: "0x[0-9a-fA-F]+",
+            },
+        ],
+    }
+}
+
+const OWN: &str = r#"---
+eip: 1
+status: Draft
+category: Core
+---
+
+## Specification
+
+| Opcode | Name       |
+|--------|------------|
+| `0x49` | `BLOBHASH` |
+| `0x4b` | `FOO`      |
+
+### Transaction type `0x03`
+"#;
+
+#[tokio::test]
+async fn collision() {
+    let other = r#"---
+eip: 2
+status: Final
+category: Core
+---
+
+## Specification
+
+| Name       | Opcode |
+|------------|--------|
+| `BLOBHASH` | `0x49` |
+"#;
+
+    let tx = r#"---
+eip: 3
+status: Review
+category: Core
+---
+
+## Transaction type 0x3
+"#;
+
+    let fetch = Memory::default()
+        .file("EIPS/eip-1.md", OWN)
+        .file("EIPS/eip-2.md", other)
+        .file("EIPS/eip-3.md", tx);
+
+    let reports = Linter::<Text<String>>::default()
+        .set_fetch(fetch)
+        .clear_lints()
+        .deny("markdown-protocol-collision", lint())
+        .check_file(Path::new("EIPS/eip-1.md"))
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-protocol-collision]: opcode `0x49` is also claimed by `eip-2.md`
+  --> EIPS/eip-1.md:11:4
+   |
+11 | | `0x49` | `BLOBHASH` |
+   |    ^^^^ claimed here
+   |
+  ::: EIPS/eip-2.md:11:17
+   |
+11 | | `BLOBHASH` | `0x49` |
+   |                 ^^^^ also claimed here
+   |
+error[markdown-protocol-collision]: transaction type `0x03` is also claimed by `eip-3.md`
+  --> EIPS/eip-1.md:14:23
+   |
+14 | ### Transaction type `0x03`
+   |                       ^^^^ claimed here
+   |
+  ::: EIPS/eip-3.md:7:21
+   |
+ 7 | ## Transaction type 0x3
+   |                     ^^^ also claimed here
+   |
+"#
+    );
+}
+
+#[tokio::test]
+async fn ignored() {
+    let withdrawn = r#"---
+eip: 2
+status: Withdrawn
+category: Core
+---
+
+| Opcode |
+|--------|
+| `0x49` |
+"#;
+
+    let interface = r#"---
+eip: 3
+status: Final
+category: Interface
+---
+
+| Opcode |
+|--------|
+| `0x4B` |
+"#;
+
+    let fetch = Memory::default()
+        .file("EIPS/eip-1.md", OWN)
+        .file("EIPS/eip-2.md", withdrawn)
+        .file("EIPS/eip-3.md", interface)
+        .file("EIPS/README.md", "hello 0x49");
+
+    let reports = Linter::<Text<String>>::default()
+        .set_fetch(fetch)
+        .clear_lints()
+        .deny("markdown-protocol-collision", lint())
+        .check_file(Path::new("EIPS/eip-1.md"))
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
@@ -24,6 +24,7 @@ header: value1
             Regex {
                 message: "boop",
                 mode: Mode::Excludes,
+                sections: None,
                 pattern: "hi",
             },
         )
@@ -38,6 +39,7 @@ header: value1
         r#"error[markdown-re]: boop
   |
 5 | [hi](https://example.com/)
+  |  ^^
   |
   = info: the pattern in question: `hi`
 "#
@@ -60,6 +62,7 @@ header: value1
             Regex {
                 message: "boop",
                 mode: Mode::Excludes,
+                sections: None,
                 pattern: "example",
             },
         )
@@ -89,6 +92,7 @@ hello
             Regex {
                 message: "boop",
                 mode: Mode::Excludes,
+                sections: None,
                 pattern: "ello",
             },
         )
@@ -103,8 +107,245 @@ hello
         r#"error[markdown-re]: boop
   |
 6 | hello
+  |  ^^^^
   |
   = info: the pattern in question: `ello`
 "#
     );
 }
+
+#[tokio::test]
+async fn excludes_annotates_matches() {
+    let src = r#"---
+header: value1
+---
+
+hello and jello
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-re",
+            Regex {
+                message: "boop",
+                mode: Mode::Excludes,
+                sections: None,
+                pattern: "ello",
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-re]: boop
+  |
+5 | hello and jello
+  |  ^^^^
+  |            ^^^^
+  |
+  = info: the pattern in question: `ello`
+"#
+    );
+}
+
+#[tokio::test]
+async fn excludes_sections() {
+    let src = r#"---
+header: value1
+---
+
+hello
+
+## Abstract
+
+hello
+
+## Specification
+
+hello
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-re",
+            Regex {
+                message: "boop",
+                mode: Mode::Excludes,
+                sections: Some(vec!["Specification"]),
+                pattern: "ello",
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-re]: boop
+   |
+13 | hello
+   |  ^^^^
+   |
+   = info: the pattern in question: `ello`
+"#
+    );
+}
+
+#[tokio::test]
+async fn excludes_rendered_across_nodes() {
+    let src = r#"---
+header: value1
+---
+
+See *EIP*-1 and
+EIP
+-2 for details, but not `EIP`-3.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-re",
+            Regex {
+                message: "boop",
+                mode: Mode::ExcludesRendered,
+                sections: None,
+                pattern: r"EIP\s*-\s*[0-9]+",
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-re]: boop
+  |
+5 | See *EIP*-1 and
+  |      ^^^^^^
+  |
+  = info: the pattern in question: `EIP\s*-\s*[0-9]+`
+error[markdown-re]: boop
+  |
+6 | EIP
+  | ^^^
+7 | -2 for details, but not `EIP`-3.
+  | ^^
+  |
+  = info: the pattern in question: `EIP\s*-\s*[0-9]+`
+"#
+    );
+}
+
+#[tokio::test]
+async fn excludes_rendered_valid() {
+    let src = r#"---
+header: value1
+---
+
+See *EIP-1* and [EIP-2](./eip-2.md).
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-re",
+            Regex {
+                message: "boop",
+                mode: Mode::ExcludesRendered,
+                sections: None,
+                pattern: r"EIP\s+[0-9]+",
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn includes_across_nodes() {
+    let src = r#"---
+header: value1
+---
+
+## Specification
+
+This extends **EIP**-20.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-re",
+            Regex {
+                message: "boop",
+                mode: Mode::Includes,
+                sections: Some(vec!["Specification"]),
+                pattern: r"EIP-20\b",
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn includes_missing() {
+    let src = r#"---
+header: value1
+---
+
+## Abstract
+
+This extends EIP-20.
+
+## Specification
+
+Nothing to see.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-re",
+            Regex {
+                message: "boop",
+                mode: Mode::Includes,
+                sections: Some(vec!["Specification"]),
+                pattern: r"EIP-20\b",
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-re]: boop
+  |
+9 | ## Specification
+  |
+  = info: the pattern in question: `EIP-20\b`
+"#
+    );
+}
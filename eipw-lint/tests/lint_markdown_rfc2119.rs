@@ -0,0 +1,167 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::markdown::Rfc2119;
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+
+const BOILERPLATE: &str = r#"The key words "MUST", "MUST NOT", "REQUIRED", "SHALL", "SHALL NOT", "SHOULD", "SHOULD NOT", "RECOMMENDED", "NOT RECOMMENDED", "MAY", and "OPTIONAL" in this document are to be interpreted as described in RFC 2119 and RFC 8174."#;
+
+fn lint() -> Rfc2119<&'static str> {
+    Rfc2119 {
+        boilerplate: BOILERPLATE,
+        normative: vec!["Specification"],
+        non_normative: vec!["Abstract", "Motivation"],
+    }
+}
+
+#[tokio::test]
+async fn valid() {
+    let src = r#"---
+header: value1
+---
+
+## Motivation
+
+Clients may want to do this.
+
+## Specification
+
+The key words "MUST", "MUST NOT", "REQUIRED", "SHALL", "SHALL NOT", "SHOULD",
+"SHOULD NOT", "RECOMMENDED", "NOT RECOMMENDED", "MAY", and "OPTIONAL" in this
+document are to be interpreted as described in [RFC 2119](https://www.ietf.org/rfc/rfc2119.txt)
+and [RFC 8174](https://www.ietf.org/rfc/rfc8174.txt).
+
+Clients MUST NOT do this, and the `must` field is OPTIONAL.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-rfc2119", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn lowercase_in_specification() {
+    let src = r#"---
+header: value1
+---
+
+## Specification
+
+The key words "MUST", "MUST NOT", "REQUIRED", "SHALL", "SHALL NOT", "SHOULD", "SHOULD NOT", "RECOMMENDED", "NOT RECOMMENDED", "MAY", and "OPTIONAL" in this document are to be interpreted as described in RFC 2119 and RFC 8174.
+
+Clients MUST do this, and should not do that. The field is optional.
+
+### Details
+
+Servers Must reply.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-rfc2119", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-rfc2119]: normative keyword `should not` is not in all capitals
+  |
+9 | Clients MUST do this, and should not do that. The field is optional.
+  |                           ^^^^^^^^^^
+  |
+  = help: use `SHOULD NOT` if this is a requirement, otherwise rephrase
+error[markdown-rfc2119]: normative keyword `Must` is not in all capitals
+   |
+13 | Servers Must reply.
+   |         ^^^^
+   |
+   = help: use `MUST` if this is a requirement, otherwise rephrase
+"#
+    );
+}
+
+#[tokio::test]
+async fn non_normative_section() {
+    let src = r#"---
+header: value1
+---
+
+## Motivation
+
+Wallets SHOULD display this.
+
+## Specification
+
+The key words "MUST", "MUST NOT", "REQUIRED", "SHALL", "SHALL NOT", "SHOULD", "SHOULD NOT", "RECOMMENDED", "NOT RECOMMENDED", "MAY", and "OPTIONAL" in this document are to be interpreted as described in RFC 2119 and RFC 8174.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-rfc2119", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-rfc2119]: normative keyword `SHOULD` used in non-normative section `Motivation`
+  |
+7 | Wallets SHOULD display this.
+  |         ^^^^^^
+  |
+  = help: rephrase without the keyword, or move the requirement into the specification
+"#
+    );
+}
+
+#[tokio::test]
+async fn missing_boilerplate() {
+    let src = r#"---
+header: value1
+---
+
+## Specification
+
+Clients MUST do this.
+
+Servers MAY do that.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-rfc2119", lint())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        format!(
+            r#"error[markdown-rfc2119]: normative keywords are used without the RFC 2119 boilerplate
+  |
+7 | Clients MUST do this.
+  |
+  = help: add this paragraph: {}
+"#,
+            BOILERPLATE
+        )
+    );
+}
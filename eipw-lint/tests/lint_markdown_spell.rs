@@ -0,0 +1,211 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::markdown::Spell;
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+
+#[tokio::test]
+async fn unknown_word() {
+    let src = r#"---
+header: value1
+---
+
+The transaction is recieved by the node.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-spell", Spell::<&str> { dictionary: vec![] })
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-spell]: unknown word `recieved`
+  |
+5 | The transaction is recieved by the node.
+  |                    ^^^^^^^^ unknown word
+  |
+  = help: did you mean `received`, `relieved`, `receiver`?
+"#
+    );
+}
+
+#[tokio::test]
+async fn project_dictionary() {
+    let src = r#"---
+header: value1
+---
+
+Copy the calldata, then hash it with keccak256 before the SSTORE.
+
+Calldata's length, and CALLDATA too.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-spell",
+            Spell {
+                dictionary: vec!["calldata", "keccak256", "SSTORE"],
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn project_dictionary_case() {
+    let src = r#"---
+header: value1
+---
+
+Avoid the sstore here.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-spell",
+            Spell {
+                dictionary: vec!["SSTORE"],
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-spell]: unknown word `sstore`
+  |
+5 | Avoid the sstore here.
+  |           ^^^^^^ unknown word
+  |
+  = help: did you mean `SSTORE`, `store`, `satire`?
+"#
+    );
+}
+
+#[tokio::test]
+async fn skips_code_links_and_html() {
+    let src = r#"---
+header: value1
+---
+
+Call `fooo_barr()` or see [the qwzx docs](https://example.com/qwzx).
+
+<div>zzyzx</div>
+
+```python
+deff fooo():
+    pass
+```
+
+Text with <span title="asdf">ghjk</span> inline HTML.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-spell", Spell::<&str> { dictionary: vec![] })
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-spell]: unknown word `ghjk`
+   |
+14 | Text with <span title="asdf">ghjk</span> inline HTML.
+   |                              ^^^^ unknown word
+   |
+   = help: did you mean `gawk`, `geek`, `ghat`?
+"#
+    );
+}
+
+#[tokio::test]
+async fn nested_and_capitalized() {
+    let src = r#"---
+header: value1
+---
+
+# Specificaton
+
+| Column | Other |
+|--------|-------|
+| ROWS   | Valeu |
+
+- Item one
+  continues thre.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-spell", Spell::<&str> { dictionary: vec![] })
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-spell]: unknown word `Specificaton`
+  |
+5 | # Specificaton
+  |   ^^^^^^^^^^^^ unknown word
+  |
+  = help: did you mean `specification`, `specifications`?
+error[markdown-spell]: unknown word `Valeu`
+  |
+9 | | ROWS   | Valeu |
+  |            ^^^^^ unknown word
+  |
+  = help: did you mean `vales`, `valet`, `value`?
+error[markdown-spell]: unknown word `thre`
+   |
+12 |   continues thre.
+   |             ^^^^ unknown word
+   |
+   = help: did you mean `tare`, `thee`, `thru`?
+"#
+    );
+}
+
+#[tokio::test]
+async fn skips_words_inside_tokens() {
+    let src = r#"---
+header: value1
+---
+
+The interface identifier is 0x80ac58cd, checked on the 2nd call.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-spell", Spell::<&str> { dictionary: vec![] })
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
@@ -0,0 +1,202 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::markdown::TableStructure;
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+
+#[tokio::test]
+async fn valid() {
+    let src = r#"---
+header: value1
+---
+
+| Name | Value \| Escaped |
+|:-----|-----------------:|
+| a    | `1`              |
+b | 2
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-table-structure",
+            TableStructure { alignment: true },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn cell_count() {
+    let src = r#"---
+header: value1
+---
+
+| Name | Value |
+|------|-------|
+| a    |
+| b    | 2     | 3 |
+| c    | 3     |
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-table-structure",
+            TableStructure { alignment: true },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-table-structure]: table row has 1 cell, but the header has 2
+  |
+7 | | a    |
+  |
+error[markdown-table-structure]: table row has 3 cells, but the header has 2
+  |
+8 | | b    | 2     | 3 |
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn empty_header() {
+    let src = r#"---
+header: value1
+---
+
+| Name |   |
+|------|---|
+| a    | 1 |
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-table-structure",
+            TableStructure { alignment: true },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-table-structure]: table header cell is empty
+  |
+5 | | Name |   |
+  |         ^^^
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn missing_header() {
+    let src = r#"---
+header: value1
+---
+
+| a | 1 |
+| b | 2 |
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-table-structure",
+            TableStructure { alignment: true },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-table-structure]: table is missing a header row
+  |
+5 | | a | 1 |
+  |
+  = help: add an alignment row (like `| --- | --- |`) after the first row
+"#
+    );
+}
+
+#[tokio::test]
+async fn alignment() {
+    let src = r#"---
+header: value1
+---
+
+| Name | Value |
+|------|
+| a    | 1     |
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-table-structure",
+            TableStructure { alignment: true },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-table-structure]: table alignment row has 1 cell, but the header has 2
+  |
+6 | |------|
+  |
+  = help: GitHub doesn't render this as a table
+"#
+    );
+}
+
+#[tokio::test]
+async fn alignment_disabled() {
+    let src = r#"---
+header: value1
+---
+
+| Name | Value |
+|------|
+| a    | 1     |
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-table-structure",
+            TableStructure { alignment: false },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
@@ -0,0 +1,240 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::fix;
+use eipw_lint::lints::markdown::{
+    FinalNewline, HardTabs, LineEndings, NonBreakingSpaces, TrailingBlankLines, TrailingWhitespace,
+};
+use eipw_lint::reporters::{Fixes, Text};
+use eipw_lint::Linter;
+
+#[tokio::test]
+async fn clean() {
+    let src = "---\nheader: value1\n---\n\nHello  \nworld.\n\n```\nindented\twith tab  \n```\n";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-line-endings", LineEndings)
+        .deny("markdown-final-newline", FinalNewline)
+        .deny("markdown-trailing-blank-lines", TrailingBlankLines)
+        .deny("markdown-hard-tabs", HardTabs)
+        .deny("markdown-non-breaking-spaces", NonBreakingSpaces)
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn line_endings() {
+    let src = "---\nheader: value1\n---\n\nHello\r\nworld.\r\n";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-line-endings", LineEndings)
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-line-endings]: line ends with `\r\n` (1 more line is affected)
+  |
+5 | Hello
+  |
+  = help: use `\n` line endings throughout the file
+"#
+    );
+}
+
+#[tokio::test]
+async fn line_endings_lone_cr() {
+    let src = "---\nheader: value1\n---\n\nHello\r\r\n";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-line-endings", LineEndings)
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    // Both carriage returns are on the same line, so it's only counted once.
+    assert_eq!(
+        reports,
+        "error[markdown-line-endings]: line ends with `\\r`\n  |\n5 | Hello\r\n  |\n  = help: use `\\n` line endings throughout the file\n"
+    );
+}
+
+#[tokio::test]
+async fn trailing_whitespace() {
+    let src = "---\nheader: value1\n---\n\nHello  \nworld. \n\n```\ncode  \n```\n";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-trailing-whitespace", TrailingWhitespace)
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-trailing-whitespace]: line has trailing whitespace
+  |
+5 | Hello  
+  |      ^^
+  |
+  = help: use a backslash (`\`) to break the line
+error[markdown-trailing-whitespace]: line has trailing whitespace
+  |
+6 | world. 
+  |       ^
+  |
+  = help: remove the trailing whitespace
+"#
+    );
+}
+
+#[tokio::test]
+async fn final_newline() {
+    let src = "---\nheader: value1\n---\n\nHello world.";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-final-newline", FinalNewline)
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-final-newline]: file does not end with a newline
+  |
+5 | Hello world.
+  |
+  = help: add a newline after the last line
+"#
+    );
+}
+
+#[tokio::test]
+async fn trailing_blank_lines() {
+    let src = "---\nheader: value1\n---\n\nHello world.\n\n\n";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-trailing-blank-lines", TrailingBlankLines)
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-trailing-blank-lines]: file ends with blank lines
+  |
+5 | Hello world.
+  |
+  = help: end the file with a single newline
+"#
+    );
+}
+
+#[tokio::test]
+async fn hard_tabs() {
+    let src = "---\nheader: value1\n---\n\n-\tone\n\t- two\n";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-hard-tabs", HardTabs)
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-hard-tabs]: line contains a hard tab
+  |
+5 | -   one
+  |  ^^^
+  |
+  = help: indent with spaces instead
+error[markdown-hard-tabs]: line contains a hard tab
+  |
+6 |     - two
+  | ^^^^
+  |
+  = help: indent with spaces instead
+"#
+    );
+}
+
+#[tokio::test]
+async fn non_breaking_spaces() {
+    let src = "---\nheader: value1\n---\n\nHello\u{a0}world and\u{a0}more.\n";
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("markdown-non-breaking-spaces", NonBreakingSpaces)
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        concat!(
+            "error[markdown-non-breaking-spaces]: line contains a non-breaking space\n",
+            "  |\n",
+            "5 | Hello\u{a0}world and\u{a0}more.\n",
+            "  |      ^\n",
+            "  |                ^\n",
+            "  |\n",
+            "  = help: use a regular space, or `&nbsp;` where the line must not break\n",
+        )
+    );
+}
+
+#[tokio::test]
+async fn apply_fixes() {
+    let src = "---\nheader: value1\n---\n\nHello  \r\nworld\u{a0}again.\r\n\n-\tEnd.\n\n";
+
+    let reporter = Linter::new(Fixes::new(Text::<String>::default()))
+        .clear_lints()
+        .deny("markdown-line-endings", LineEndings)
+        .deny("markdown-trailing-whitespace", TrailingWhitespace)
+        .deny("markdown-final-newline", FinalNewline)
+        .deny("markdown-trailing-blank-lines", TrailingBlankLines)
+        .deny("markdown-hard-tabs", HardTabs)
+        .deny("markdown-non-breaking-spaces", NonBreakingSpaces)
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap();
+
+    let (_, fixes) = reporter.into_parts();
+    let (fixed, skipped) = fix::apply(src, &fixes);
+
+    assert!(skipped.is_empty());
+    assert_eq!(
+        fixed,
+        "---\nheader: value1\n---\n\nHello\\\nworld again.\n\n-   End.\n"
+    );
+}
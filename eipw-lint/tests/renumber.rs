@@ -0,0 +1,114 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use assert_matches::assert_matches;
+
+use eipw_lint::lints::markdown::AssetsUnused;
+use eipw_lint::lints::preamble::FileName;
+use eipw_lint::lints::DefaultLint;
+use eipw_lint::renumber::{Error, Renumber};
+
+#[test]
+fn rewrite() {
+    let src = "---
+eip: 7777
+title: Hello
+requires: 7777
+---
+
+See [the diagram](../assets/eip-7777/diagram.png) and [this section](./eip-7777.md#spec).
+
+Unrelated [link](./eip-77770.md), [another](../assets/eip-20/x.png).
+
+[ref]: ../assets/eip-7777/z.svg
+";
+
+    let actual = Renumber::<&str>::default().rewrite(src, 8000).unwrap();
+
+    assert_eq!(
+        actual,
+        "---
+eip: 8000
+title: Hello
+requires: 7777
+---
+
+See [the diagram](../assets/eip-8000/diagram.png) and [this section](./eip-8000.md#spec).
+
+Unrelated [link](./eip-77770.md), [another](../assets/eip-20/x.png).
+
+[ref]: ../assets/eip-8000/z.svg
+"
+    );
+}
+
+#[test]
+fn rewrite_html() {
+    let src = r#"---
+eip: 9999
+---
+
+<img src="../assets/eip-9999/a.png" alt="A">
+<a href='./eip-9999.md#spec'>spec</a> <IMG SRC=../assets/eip-9999/b.png>
+<img src="../assets/eip-99990/c.png">
+"#;
+
+    let actual = Renumber::<&str>::default().rewrite(src, 10000).unwrap();
+
+    assert_eq!(
+        actual,
+        r#"---
+eip: 10000
+---
+
+<img src="../assets/eip-10000/a.png" alt="A">
+<a href='./eip-10000.md#spec'>spec</a> <IMG SRC=../assets/eip-10000/b.png>
+<img src="../assets/eip-99990/c.png">
+"#
+    );
+}
+
+#[test]
+fn number() {
+    let renumber = Renumber::<&str>::default();
+
+    assert_eq!(renumber.number("---\neip: 12\n---\n").unwrap(), 12);
+    assert_matches!(
+        renumber.number("---\ntitle: x\n---\n"),
+        Err(Error::Missing { .. })
+    );
+    assert_matches!(
+        renumber.number("---\neip: draft\n---\n"),
+        Err(Error::Number { .. })
+    );
+}
+
+#[test]
+fn from_lints() {
+    let lints = [
+        DefaultLint::PreambleFileName(FileName {
+            name: "rip",
+            prefix: "rip-",
+            suffix: ".markdown",
+        }),
+        DefaultLint::MarkdownAssetsUnused(AssetsUnused {
+            name: "rip",
+            assets: "../static",
+            prefix: "rip-",
+        }),
+    ];
+
+    let renumber = Renumber::from_lints(&lints);
+
+    assert_eq!(renumber.file_name(3), "rip-3.markdown");
+    assert_eq!(renumber.assets_dir(3), "../static/rip-3");
+    assert_eq!(
+        renumber
+            .rewrite("---\nrip: 1\n---\n[x](rip-1.markdown)\n", 3)
+            .unwrap(),
+        "---\nrip: 3\n---\n[x](rip-3.markdown)\n"
+    );
+}
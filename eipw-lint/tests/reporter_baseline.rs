@@ -0,0 +1,95 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::Trim;
+use eipw_lint::reporters::baseline::Entry;
+use eipw_lint::reporters::{Baseline, Text};
+use eipw_lint::Linter;
+
+async fn lint(src: &str, known: Vec<Entry>) -> Baseline<Text<String>> {
+    Linter::new(Baseline::new(Text::<String>::default(), known))
+        .clear_lints()
+        .deny("preamble-trim", Trim)
+        .check_slice(Some("eip-1.md"), src)
+        .run()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn record() {
+    let src = "---\nauthor:  A\ntitle:  Hello\n---\nhello world";
+
+    let reporter = lint(src, vec![]).await;
+    let seen = reporter.seen();
+
+    assert_eq!(seen.len(), 2);
+    assert!(seen.iter().all(|e| e.origin.as_deref() == Some("eip-1.md")));
+    assert!(seen
+        .iter()
+        .all(|e| e.slug.as_deref() == Some("preamble-trim")));
+    assert_ne!(seen[0].fingerprint, seen[1].fingerprint);
+    assert!(reporter.stale(["eip-1.md"]).is_empty());
+}
+
+#[tokio::test]
+async fn suppress_shifted() {
+    let before = "---\nauthor:  A\ntitle:  Hello\n---\nhello world";
+    let known = lint(before, vec![]).await.seen();
+
+    // Same problems, on different lines.
+    let after = "---\neip: 1\ntitle:  Hello\nauthor:  A\n---\nhello world";
+    let reporter = lint(after, known).await;
+
+    assert!(reporter.stale(["eip-1.md"]).is_empty());
+    assert_eq!(reporter.into_inner().into_inner(), "");
+}
+
+#[tokio::test]
+async fn new_and_stale() {
+    let before = "---\nauthor:  A\ntitle:  Hello\n---\nhello world";
+    let known = lint(before, vec![]).await.seen();
+
+    let after = "---\nauthor: A\ntitle:  Hello\nstatus:  Draft\n---\nhello world";
+    let reporter = lint(after, known).await;
+
+    let stale = reporter.stale(["eip-1.md"]);
+    assert_eq!(stale.len(), 1);
+    assert_eq!(stale[0].slug.as_deref(), Some("preamble-trim"));
+
+    assert_eq!(
+        reporter.into_inner().into_inner(),
+        r#"error[preamble-trim]: preamble header `status` has extra whitespace
+ --> eip-1.md:4:8
+  |
+4 | status:  Draft
+  |        ^^^^^^^ value has extra whitespace
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn stale_only_checked() {
+    let src = "---\nauthor:  A\ntitle:  Hello\n---\nhello world";
+    let mut known = lint(src, vec![]).await.seen();
+
+    for entry in known.clone() {
+        known.push(Entry {
+            origin: Some("eip-2.md".into()),
+            ..entry
+        });
+    }
+
+    let fixed = "---\nauthor: A\ntitle: Hello\n---\nhello world";
+    let reporter = lint(fixed, known).await;
+
+    let stale = reporter.stale(["eip-1.md"]);
+    assert_eq!(stale.len(), 2);
+    assert!(stale
+        .iter()
+        .all(|e| e.origin.as_deref() == Some("eip-1.md")));
+}
@@ -0,0 +1,37 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::{Required, Trim};
+use eipw_lint::reporters::Checkstyle;
+use eipw_lint::Linter;
+
+#[tokio::test]
+async fn grouped() {
+    let src = "---\neip: 1\ntitle:  <Hello> & \"Goodbye\"\nauthor:  A\n---\nhello world";
+
+    let reports = Linter::new(Checkstyle::default())
+        .clear_lints()
+        .deny("preamble-req", Required(vec!["eip", "status"]))
+        .warn("preamble-trim", Trim)
+        .check_slice(Some("eip-1.md"), src)
+        .run()
+        .await
+        .unwrap()
+        .to_string();
+
+    assert_eq!(
+        reports,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<checkstyle version="4.3">
+  <file name="eip-1.md">
+    <error line="1" severity="error" message="preamble is missing header(s): `status`" source="eipw.preamble-req"/>
+    <error line="3" column="7" severity="warning" message="preamble header `title` has extra whitespace" source="eipw.preamble-trim"/>
+    <error line="4" column="8" severity="warning" message="preamble header `author` has extra whitespace" source="eipw.preamble-trim"/>
+  </file>
+</checkstyle>
+"#
+    );
+}
@@ -0,0 +1,54 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::{Required, Trim};
+use eipw_lint::reporters::{AdditionalHelp, Github};
+use eipw_lint::Linter;
+
+#[tokio::test]
+async fn annotation() {
+    let src = "---\neip: 1\ntitle:  Hello, 100%\n---\nhello world";
+
+    let reports = Linter::new(Github::<String>::default())
+        .clear_lints()
+        .warn("preamble-trim", Trim)
+        .check_slice(Some("EIPS/eip-1.md"), src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        "::warning file=EIPS/eip-1.md,line=3,col=7,endColumn=19,title=preamble-trim::\
+            preamble header `title` has extra whitespace\n"
+    );
+}
+
+#[tokio::test]
+async fn footer() {
+    let src = "---\neip: 1\n---\nhello world";
+
+    let reporter = AdditionalHelp::new(Github::<String>::default(), |t: &str| {
+        Ok(format!("see https://example.com/{}/", t))
+    });
+
+    let reports = Linter::new(reporter)
+        .clear_lints()
+        .deny("preamble-req", Required(vec!["eip", "title"]))
+        .check_slice(Some("eip-1.md"), src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        "::error file=eip-1.md,line=1,title=preamble-req::\
+            preamble is missing header(s): `title`%0Ahelp: see https://example.com/preamble-req/\n"
+    );
+}
@@ -0,0 +1,55 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::{Required, Trim};
+use eipw_lint::reporters::Junit;
+use eipw_lint::Linter;
+
+#[tokio::test]
+async fn grouped() {
+    let src = "---\neip: 1\ntitle:  <Hello> & \"Goodbye\"\nauthor:  A\n---\nhello world";
+
+    let reports = Linter::new(Junit::default())
+        .clear_lints()
+        .deny("preamble-req", Required(vec!["eip", "status"]))
+        .warn("preamble-trim", Trim)
+        .check_slice(Some("eip-1.md"), src)
+        .run()
+        .await
+        .unwrap()
+        .to_string();
+
+    assert_eq!(
+        reports,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="eipw">
+  <testsuite name="eip-1.md" tests="2" failures="1" errors="0">
+    <testcase name="preamble-req" classname="eip-1.md">
+      <failure type="error" message="preamble is missing header(s): `status`">error[preamble-req]: preamble is missing header(s): `status`
+--&gt; eip-1.md
+ |
+ |</failure>
+    </testcase>
+    <testcase name="preamble-trim" classname="eip-1.md">
+      <system-out>warning[preamble-trim]: preamble header `title` has extra whitespace
+ --&gt; eip-1.md:3:7
+  |
+3 | title:  &lt;Hello&gt; &amp; &quot;Goodbye&quot;
+  |       --------------------- value has extra whitespace
+  |
+warning[preamble-trim]: preamble header `author` has extra whitespace
+ --&gt; eip-1.md:4:8
+  |
+4 | author:  A
+  |        --- value has extra whitespace
+  |
+</system-out>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+    );
+}
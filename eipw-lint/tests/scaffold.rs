@@ -0,0 +1,114 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::markdown::{SectionOrder, SectionRequired};
+use eipw_lint::lints::preamble::regex::Mode;
+use eipw_lint::lints::preamble::{Date, OneOf, Order, Regex, RequiredIfEq};
+use eipw_lint::lints::DefaultLint;
+use eipw_lint::reporters::Text;
+use eipw_lint::scaffold::Scaffold;
+use eipw_lint::{default_lints_enum, Linter};
+
+#[test]
+fn generate() {
+    let lints = [
+        DefaultLint::PreambleRequired {
+            names: eipw_lint::lints::preamble::Required(vec!["eip", "title", "type", "created"]),
+        },
+        DefaultLint::PreambleOrder {
+            names: Order(vec![
+                "eip", "title", "author", "type", "category", "created",
+            ]),
+        },
+        DefaultLint::PreambleOneOf(OneOf {
+            name: "type",
+            values: vec!["Standards Track", "Meta"],
+        }),
+        DefaultLint::PreambleRequiredIfEq(RequiredIfEq {
+            when: "type",
+            equals: "Standards Track",
+            then: "category",
+        }),
+        DefaultLint::PreambleDate {
+            name: Date("created"),
+        },
+        DefaultLint::MarkdownSectionRequired {
+            sections: SectionRequired(vec!["Specification", "Abstract"]),
+        },
+        DefaultLint::MarkdownSectionOrder {
+            sections: SectionOrder(vec!["Abstract", "Motivation", "Specification"]),
+        },
+    ];
+
+    let scaffold = Scaffold::from_lints(&lints);
+    let actual = scaffold.generate(&[("author", "A (@a)"), ("created", "2023-01-01")]);
+
+    assert_eq!(
+        actual,
+        "---
+eip: Replace with the eip
+title: Replace with the title
+author: A (@a)
+type: Standards Track
+category: Replace with the category
+created: 2023-01-01
+---
+
+## Abstract
+
+Replace with the contents of the Abstract section.
+
+## Specification
+
+Replace with the contents of the Specification section.
+"
+    );
+}
+
+#[tokio::test]
+async fn default_lints() {
+    let lints: Vec<_> = default_lints_enum().map(|(_, l)| l).collect();
+    let scaffold = Scaffold::from_lints(&lints);
+
+    let src = scaffold.generate(&[
+        ("eip", "7777"),
+        ("title", "Hello"),
+        ("status", "Draft"),
+        ("type", "Standards Track"),
+        ("category", "Core"),
+    ]);
+
+    let reports = Linter::<Text<String>>::default()
+        .check_slice(None, &src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[test]
+fn regex_placeholder() {
+    let lints = [
+        DefaultLint::PreambleRequired {
+            names: eipw_lint::lints::preamble::Required(vec!["discussions-to"]),
+        },
+        DefaultLint::PreambleRegex(Regex {
+            name: "discussions-to",
+            mode: Mode::Includes,
+            pattern: "^https://example.com/t/[^/]+/[0-9]+$",
+            message: "",
+        }),
+    ];
+
+    let scaffold = Scaffold::from_lints(&lints);
+
+    assert_eq!(
+        scaffold.generate(&[]),
+        "---\ndiscussions-to: https://example.com/t/a/0\n---\n"
+    );
+}
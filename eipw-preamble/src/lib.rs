@@ -9,6 +9,10 @@
 //! See [`Preamble`] for more details.
 #![warn(missing_docs)]
 
+mod owned;
+
+pub use self::owned::{OwnedField, OwnedPreamble};
+
 use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet};
 
 use regex::Regex;
@@ -30,6 +34,14 @@ impl<'a> ParseErrors<'a> {
     pub fn into_errors(self) -> Vec<Snippet<'a>> {
         self.errors
     }
+
+    /// Line number of the first problem, if known.
+    pub fn line_start(&self) -> Option<usize> {
+        self.errors
+            .first()
+            .and_then(|s| s.slices.first())
+            .map(|s| s.line_start)
+    }
 }
 
 /// Errors that can arise from [`Preamble::split`].
@@ -47,6 +59,32 @@ pub enum SplitError {
     MissingEnd,
 }
 
+/// Errors that can arise from [`OwnedPreamble::from_source`].
+#[derive(Debug, Snafu)]
+#[non_exhaustive]
+pub enum SourceError {
+    /// The preamble couldn't be found.
+    #[snafu(display("unable to find preamble"), context(false))]
+    Split {
+        /// Why the preamble couldn't be found.
+        source: SplitError,
+    },
+    /// A preamble header couldn't be parsed.
+    #[snafu(display("invalid preamble header on line {}", line))]
+    Preamble {
+        /// Line of the first invalid header, or zero if unknown.
+        line: usize,
+    },
+}
+
+impl From<ParseErrors<'_>> for SourceError {
+    fn from(errors: ParseErrors<'_>) -> Self {
+        Self::Preamble {
+            line: errors.line_start().unwrap_or_default(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 struct Fields<'a> {
     vec: Vec<Field<'a>>,
@@ -0,0 +1,329 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::{ParseErrors, Preamble, SourceError, SplitError};
+
+use std::fmt;
+
+/// A field from an [`OwnedPreamble`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedField {
+    name: String,
+    value: String,
+
+    /// The line this field was parsed from, until it is changed.
+    source: Option<String>,
+}
+
+impl OwnedField {
+    /// Creates a field that will be serialized as `name: value`.
+    pub fn new<N, V>(name: N, value: V) -> Self
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            source: None,
+        }
+    }
+
+    /// Key (before the colon) of this preamble field.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Value (after the colon) of this preamble field, without surrounding
+    /// whitespace.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the value of this field. The field will be serialized as
+    /// `name: value`, even if `value` is unchanged.
+    pub fn set_value<V>(&mut self, value: V)
+    where
+        V: Into<String>,
+    {
+        self.value = value.into();
+        self.source = None;
+    }
+}
+
+impl fmt::Display for OwnedField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            Some(source) => write!(f, "{}", source),
+            None if self.value.is_empty() => write!(f, "{}:", self.name),
+            None => write!(f, "{}: {}", self.name, self.value),
+        }
+    }
+}
+
+/// An editable, ordered list of fields from a preamble.
+///
+/// Unlike [`Preamble`], fields are owned and can be inserted, removed,
+/// reordered, and updated. Serializing with [`fmt::Display`] reproduces
+/// untouched fields exactly as they were parsed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OwnedPreamble {
+    fields: Vec<OwnedField>,
+}
+
+impl OwnedPreamble {
+    /// Parse some preamble text (usually extracted with [`Preamble::split`].)
+    pub fn parse(text: &str) -> Result<Self, ParseErrors<'_>> {
+        Ok(Self::from(&Preamble::parse(None, text)?))
+    }
+
+    /// Splits `source` (a complete proposal) and parses its preamble. Returns
+    /// the preamble along with the rest of the proposal.
+    pub fn from_source(source: &str) -> Result<(Self, &str), SourceError> {
+        let (preamble, body) = Preamble::split(source)?;
+        Ok((Self::parse(preamble)?, body))
+    }
+
+    /// Provides an iterator over the fields, in order.
+    pub fn fields(&self) -> impl '_ + Iterator<Item = &OwnedField> {
+        self.fields.iter()
+    }
+
+    /// Provides an iterator over mutable fields, in order.
+    pub fn fields_mut(&mut self) -> impl '_ + Iterator<Item = &mut OwnedField> {
+        self.fields.iter_mut()
+    }
+
+    /// Number of fields in the preamble.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Whether the preamble has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        // Like `Preamble::by_name`, prefer the last of any duplicates.
+        self.fields.iter().rposition(|f| f.name == name)
+    }
+
+    /// Get a field by its name, or `None` if it isn't present.
+    pub fn by_name(&self, name: &str) -> Option<&OwnedField> {
+        self.position(name).map(|idx| &self.fields[idx])
+    }
+
+    /// Get a mutable field by its name, or `None` if it isn't present.
+    pub fn by_name_mut(&mut self, name: &str) -> Option<&mut OwnedField> {
+        self.position(name).map(move |idx| &mut self.fields[idx])
+    }
+
+    /// Get a field by its position (zero-indexed.)
+    pub fn by_index(&self, index: usize) -> Option<&OwnedField> {
+        self.fields.get(index)
+    }
+
+    /// Sets the value of the field named `name`, appending a new field if
+    /// there isn't one. Returns the previous value, if any.
+    pub fn set<N, V>(&mut self, name: N, value: V) -> Option<String>
+    where
+        N: Into<String> + AsRef<str>,
+        V: Into<String>,
+    {
+        match self.by_name_mut(name.as_ref()) {
+            Some(field) => {
+                let previous = std::mem::take(&mut field.value);
+                field.set_value(value);
+                Some(previous)
+            }
+            None => {
+                self.fields.push(OwnedField::new(name, value));
+                None
+            }
+        }
+    }
+
+    /// Inserts `field` at position `index`, shifting later fields down.
+    ///
+    /// Panics if `index` is greater than [`OwnedPreamble::len`].
+    pub fn insert(&mut self, index: usize, field: OwnedField) {
+        self.fields.insert(index, field);
+    }
+
+    /// Removes and returns the field named `name`, if present.
+    pub fn remove(&mut self, name: &str) -> Option<OwnedField> {
+        self.position(name).map(|idx| self.fields.remove(idx))
+    }
+
+    /// Sorts the fields named in `order` into that order, among the positions
+    /// they already occupy. Fields not named in `order` keep their position.
+    pub fn reorder<S>(&mut self, order: &[S])
+    where
+        S: AsRef<str>,
+    {
+        let rank = |f: &OwnedField| order.iter().position(|o| o.as_ref() == f.name);
+
+        let slots: Vec<_> = self
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| rank(f).is_some())
+            .map(|(idx, _)| idx)
+            .collect();
+
+        let mut ordered: Vec<_> = slots.iter().map(|idx| self.fields[*idx].clone()).collect();
+        ordered.sort_by_key(rank);
+
+        for (slot, field) in slots.into_iter().zip(ordered) {
+            self.fields[slot] = field;
+        }
+    }
+
+    /// Replaces the preamble of `source` (a complete proposal) with this one.
+    pub fn splice(&self, source: &str) -> Result<String, SplitError> {
+        let (preamble, _) = Preamble::split(source)?;
+
+        // `split` rejects anything before the opening `---\n`.
+        let start = "---\n".len();
+        let end = start + preamble.len();
+
+        Ok(format!("{}{}{}", &source[..start], self, &source[end..]))
+    }
+}
+
+impl fmt::Display for OwnedPreamble {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, field) in self.fields.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", field)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> From<&Preamble<'a>> for OwnedPreamble {
+    fn from(preamble: &Preamble<'a>) -> Self {
+        let fields = preamble
+            .fields()
+            .map(|f| OwnedField {
+                name: f.name().to_owned(),
+                value: f.value().trim().to_owned(),
+                source: Some(f.source().to_owned()),
+            })
+            .collect();
+
+        Self { fields }
+    }
+}
+
+impl FromIterator<OwnedField> for OwnedPreamble {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = OwnedField>,
+    {
+        Self {
+            fields: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let input = "eip:  1 \ntitle:Hello\r\nauthor: A (@a),B (@b)";
+        let preamble = OwnedPreamble::parse(input).unwrap();
+
+        assert_eq!(preamble.to_string(), input);
+        assert_eq!(preamble.by_name("eip").unwrap().value(), "1");
+    }
+
+    #[test]
+    fn from_source() {
+        let (preamble, body) = OwnedPreamble::from_source("---\neip: 1\n---\nBody").unwrap();
+
+        assert_eq!(preamble.to_string(), "eip: 1");
+        assert_eq!(body, "Body");
+
+        assert!(matches!(
+            OwnedPreamble::from_source("---\neip: 1\nbroken\n---\n"),
+            Err(SourceError::Preamble { line: 3 })
+        ));
+        assert!(matches!(
+            OwnedPreamble::from_source("no preamble"),
+            Err(SourceError::Split { .. })
+        ));
+    }
+
+    #[test]
+    fn set_existing() {
+        let mut preamble = OwnedPreamble::parse("eip: 1\nstatus:  Draft\ntype: Meta").unwrap();
+
+        let previous = preamble.set("status", "Review");
+
+        assert_eq!(previous.as_deref(), Some("Draft"));
+        assert_eq!(preamble.to_string(), "eip: 1\nstatus: Review\ntype: Meta");
+    }
+
+    #[test]
+    fn set_new() {
+        let mut preamble = OwnedPreamble::parse("eip:  1").unwrap();
+
+        assert_eq!(preamble.set("last-call-deadline", "2023-01-01"), None);
+        assert_eq!(
+            preamble.to_string(),
+            "eip:  1\nlast-call-deadline: 2023-01-01"
+        );
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut preamble = OwnedPreamble::parse("eip: 1\ntitle: Hello\ncreated: 2020").unwrap();
+
+        let removed = preamble.remove("title").unwrap();
+        assert_eq!(removed.name(), "title");
+        assert_eq!(preamble.remove("title"), None);
+
+        preamble.insert(1, OwnedField::new("requires", ""));
+        preamble
+            .by_name_mut("requires")
+            .unwrap()
+            .set_value("20, 55");
+
+        assert_eq!(
+            preamble.to_string(),
+            "eip: 1\nrequires: 20, 55\ncreated: 2020"
+        );
+    }
+
+    #[test]
+    fn reorder() {
+        let mut preamble = OwnedPreamble::parse("title: x\nfoo:bar\neip: 1\nauthor: a").unwrap();
+
+        preamble.reorder(&["eip", "title", "author"]);
+
+        assert_eq!(preamble.to_string(), "eip: 1\nfoo:bar\ntitle: x\nauthor: a");
+    }
+
+    #[test]
+    fn splice() {
+        let source = "---\neip: 1\nstatus: Draft\n---\n\nBody\n";
+        let (text, _) = Preamble::split(source).unwrap();
+
+        let mut preamble = OwnedPreamble::parse(text).unwrap();
+        preamble.set("status", "Final");
+
+        assert_eq!(
+            preamble.splice(source).unwrap(),
+            "---\neip: 1\nstatus: Final\n---\n\nBody\n"
+        );
+    }
+}
@@ -6,21 +6,36 @@
 
 use annotate_snippets::snippet::Snippet;
 
-use clap::{Parser, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 
+use eipw_lint::catalog::Entry;
+use eipw_lint::fix::Fix;
+use eipw_lint::format::Formatter;
+use eipw_lint::graph::Graph;
 use eipw_lint::lints::DefaultLint;
 use eipw_lint::modifiers::DefaultModifier;
+use eipw_lint::renumber::Renumber;
+use eipw_lint::reporters::baseline::Entry as BaselineEntry;
 use eipw_lint::reporters::count::Count;
-use eipw_lint::reporters::{AdditionalHelp, Json, Reporter, Text};
+use eipw_lint::reporters::{
+    AdditionalHelp, Baseline, Checkstyle, Fixes, Github, Json, Junit, Reporter, Text,
+};
+use eipw_lint::scaffold::Scaffold;
 use eipw_lint::{default_lints, default_lints_enum, default_modifiers_enum, Linter};
 
+use eipw_preamble::Preamble;
+
 use serde::{Deserialize, Serialize};
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, Parser)]
+#[clap(args_conflicts_with_subcommands(true), subcommand_negates_reqs(true))]
 struct Opts {
+    #[clap(subcommand)]
+    command: Option<Command>,
+
     /// Print the default configuration.
     #[clap(exclusive(true), long)]
     defaults: bool,
@@ -56,12 +71,181 @@ struct Opts {
     /// Path to file defining alternate default lints.
     #[clap(long, short('c'))]
     config: Option<PathBuf>,
+
+    /// Check again whenever a source, or a file it depends on, changes.
+    #[clap(long, conflicts_with_all(["format", "baseline", "write_baseline", "fix"]))]
+    watch: bool,
+
+    /// Apply machine-applicable fixes suggested by lints to the sources.
+    /// Problems are still reported as found before fixing.
+    #[clap(long)]
+    fix: bool,
+
+    /// Only report problems not recorded in this baseline file.
+    #[clap(long)]
+    baseline: Option<PathBuf>,
+
+    /// Record every problem found in this baseline file.
+    #[clap(long)]
+    write_baseline: Option<PathBuf>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Rewrite proposals into canonical form.
+    Fmt(FmtOpts),
+
+    /// Generate a skeleton proposal that satisfies the configured lints.
+    New(Box<NewOpts>),
+
+    /// Move a proposal to a newly assigned number.
+    Renumber(RenumberOpts),
+
+    /// Export the preambles of proposals as JSON or CSV.
+    Catalog(CatalogOpts),
+
+    /// Export the dependencies between proposals as a graph.
+    Graph(GraphOpts),
+}
+
+#[derive(Debug, Args)]
+struct FmtOpts {
+    /// Files and/or directories to format.
+    #[clap(required(true))]
+    sources: Vec<PathBuf>,
+
+    /// Print a diff and fail instead of writing files that aren't formatted.
+    #[clap(long)]
+    check: bool,
+
+    /// Path to file defining alternate default lints.
+    #[clap(long, short('c'))]
+    config: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+struct NewOpts {
+    /// Value for the `eip` header.
+    #[clap(long)]
+    eip: Option<String>,
+
+    /// Value for the `title` header.
+    #[clap(long)]
+    title: Option<String>,
+
+    /// Value for the `description` header.
+    #[clap(long)]
+    description: Option<String>,
+
+    /// Value for the `author` header.
+    #[clap(long)]
+    author: Option<String>,
+
+    /// Value for the `discussions-to` header.
+    #[clap(long)]
+    discussions_to: Option<String>,
+
+    /// Value for the `status` header.
+    #[clap(long)]
+    status: Option<String>,
+
+    /// Value for the `type` header.
+    #[clap(long("type"))]
+    type_: Option<String>,
+
+    /// Value for the `category` header.
+    #[clap(long)]
+    category: Option<String>,
+
+    /// Values for other headers, as `name=value`.
+    #[clap(long, value_parser = parse_header)]
+    header: Vec<(String, String)>,
+
+    /// Write the proposal to this file instead of standard output.
+    #[clap(long, short('o'))]
+    output: Option<PathBuf>,
+
+    /// Path to file defining alternate default lints.
+    #[clap(long, short('c'))]
+    config: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+struct RenumberOpts {
+    /// Proposal to renumber.
+    source: PathBuf,
+
+    /// Newly assigned number.
+    number: u64,
+
+    /// Path to file defining alternate default lints.
+    #[clap(long, short('c'))]
+    config: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+struct CatalogOpts {
+    /// Files and/or directories to read.
+    #[clap(required(true))]
+    sources: Vec<PathBuf>,
+
+    /// Output format.
+    #[clap(long, value_enum, default_value_t)]
+    format: CatalogFormat,
+}
+
+#[derive(ValueEnum, Clone, Debug, Default)]
+enum CatalogFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Args)]
+struct GraphOpts {
+    /// Files and/or directories to read.
+    #[clap(required(true))]
+    sources: Vec<PathBuf>,
+
+    /// Output format.
+    #[clap(long, value_enum, default_value_t)]
+    format: GraphFormat,
+
+    /// Include proposals mentioned in the body, not just `requires`.
+    #[clap(long)]
+    references: bool,
+
+    /// Only show what this proposal depends on, directly or transitively.
+    #[clap(long, conflicts_with("dependents_of"))]
+    deps_of: Option<u64>,
+
+    /// Only show what depends on this proposal, directly or transitively.
+    #[clap(long)]
+    dependents_of: Option<u64>,
+}
+
+#[derive(ValueEnum, Clone, Debug, Default)]
+enum GraphFormat {
+    #[default]
+    Dot,
+    Mermaid,
+    Json,
+}
+
+fn parse_header(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((name, value)) => Ok((name.trim().to_owned(), value.trim().to_owned())),
+        None => Err(format!("expected `name=value`, found `{}`", arg)),
+    }
 }
 
 #[derive(ValueEnum, Clone, Debug)]
 enum Format {
     Text,
     Json,
+    Github,
+    Junit,
+    Checkstyle,
 }
 
 impl Default for Format {
@@ -74,6 +258,9 @@ impl Default for Format {
 enum EitherReporter {
     Json(Json),
     Text(Text<String>),
+    Github(Github<String>),
+    Junit(Junit),
+    Checkstyle(Checkstyle),
 }
 
 impl Reporter for EitherReporter {
@@ -81,6 +268,19 @@ impl Reporter for EitherReporter {
         match self {
             Self::Json(j) => j.report(snippet),
             Self::Text(s) => s.report(snippet),
+            Self::Github(g) => g.report(snippet),
+            Self::Junit(j) => j.report(snippet),
+            Self::Checkstyle(c) => c.report(snippet),
+        }
+    }
+
+    fn fix(&self, fix: Fix) -> Result<(), eipw_lint::reporters::Error> {
+        match self {
+            Self::Json(j) => j.fix(fix),
+            Self::Text(s) => s.fix(fix),
+            Self::Github(g) => g.fix(fix),
+            Self::Junit(j) => j.fix(fix),
+            Self::Checkstyle(c) => c.fix(fix),
         }
     }
 }
@@ -167,59 +367,486 @@ async fn collect_sources(sources: Vec<PathBuf>) -> Result<Vec<PathBuf>, std::io:
     Ok(output)
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct Lints {
-    lints: HashMap<String, DefaultLint<String>>,
+/// Files `eipw fmt` couldn't format, or found unformatted with `--check`.
+#[derive(Debug, Default)]
+struct FmtFailures {
+    unformatted: usize,
+    unparsed: usize,
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn format_sources<S>(
+    _formatter: &Formatter<S>,
+    _sources: Vec<PathBuf>,
+    _check: bool,
+) -> Result<(), FmtFailures>
+where
+    S: AsRef<str>,
+{
+    todo!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn format_sources<S>(
+    formatter: &Formatter<S>,
+    sources: Vec<PathBuf>,
+    check: bool,
+) -> Result<(), FmtFailures>
+where
+    S: AsRef<str>,
+{
+    use tokio::fs;
+
+    let mut failed = FmtFailures::default();
+
+    for source in sources {
+        let original = fs::read_to_string(&source)
+            .await
+            .expect("couldn't read source file");
+
+        let formatted = match formatter.format(&original) {
+            Ok(f) => f,
+            Err(e) => {
+                eprintln!("{}: {}", source.display(), e);
+                failed.unparsed += 1;
+                continue;
+            }
+        };
+
+        if formatted == original {
+            continue;
+        }
+
+        if !check {
+            fs::write(&source, formatted)
+                .await
+                .expect("couldn't write source file");
+            continue;
+        }
+
+        failed.unformatted += 1;
+
+        let patch = diffy::create_patch(&original, &formatted).to_string();
+        let hunks = patch
+            .strip_prefix("--- original\n+++ modified\n")
+            .unwrap_or(&patch);
+
+        print!("--- {0}\n+++ {0}\n{1}", source.display(), hunks);
+    }
+
+    if failed.unformatted > 0 || failed.unparsed > 0 {
+        Err(failed)
+    } else {
+        Ok(())
+    }
 }
 
 #[cfg_attr(target_arch = "wasm32", tokio::main(flavor = "current_thread"))]
 #[cfg_attr(not(target_arch = "wasm32"), tokio::main)]
-async fn run(opts: Opts) -> Result<(), usize> {
-    if opts.list_lints {
-        list_lints();
-        return Ok(());
+async fn fmt(opts: FmtOpts) -> Result<(), FmtFailures> {
+    let sources = collect_sources(opts.sources).await.unwrap();
+
+    let options = match opts.config {
+        Some(ref path) => read_config(path).await,
+        None => Options::default(),
+    };
+
+    match options.lints {
+        Some(lints) => {
+            let formatter = Formatter::from_lints(lints.values());
+            format_sources(&formatter, sources, opts.check).await
+        }
+        None => {
+            let lints: Vec<_> = default_lints_enum().map(|(_, l)| l).collect();
+            let formatter = Formatter::from_lints(&lints);
+            format_sources(&formatter, sources, opts.check).await
+        }
     }
+}
 
-    if opts.defaults {
-        defaults();
-        return Ok(());
+#[cfg_attr(target_arch = "wasm32", tokio::main(flavor = "current_thread"))]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::main)]
+async fn new(opts: NewOpts) -> Result<(), usize> {
+    let mut values = opts.header;
+
+    let named = [
+        ("eip", opts.eip),
+        ("title", opts.title),
+        ("description", opts.description),
+        ("author", opts.author),
+        ("discussions-to", opts.discussions_to),
+        ("status", opts.status),
+        ("type", opts.type_),
+        ("category", opts.category),
+    ];
+
+    for (name, value) in named {
+        if let Some(value) = value {
+            values.push((name.to_owned(), value));
+        }
+    }
+
+    let values: Vec<_> = values
+        .iter()
+        .map(|(n, v)| (n.as_str(), v.as_str()))
+        .collect();
+
+    let options = match opts.config {
+        Some(ref path) => read_config(path).await,
+        None => Options::default(),
+    };
+
+    let proposal = match options.lints {
+        Some(ref lints) => Scaffold::from_lints(lints.values()).generate(&values),
+        None => {
+            let lints: Vec<_> = default_lints_enum().map(|(_, l)| l).collect();
+            Scaffold::from_lints(&lints).generate(&values)
+        }
+    };
+
+    // Some headers (eg. `discussions-to`) can't be guessed, so point out
+    // whatever the author still needs to fill in.
+    let origin = opts
+        .output
+        .as_ref()
+        .map(|p| p.to_string_lossy().into_owned());
+
+    let reporter = Count::new(Text::<String>::default());
+    let linter = match opts.config {
+        Some(_) => Linter::with_options(reporter, options.to_iters()),
+        None => Linter::new(reporter),
+    };
+
+    let reporter = linter
+        .check_slice(origin.as_deref(), &proposal)
+        .run()
+        .await
+        .unwrap();
+
+    let n_errors = reporter.counts().error;
+
+    match opts.output {
+        Some(ref path) => write_new(path, &proposal).await,
+        None => print!("{}", proposal),
+    }
+
+    eprint!("{}", reporter.into_inner().into_inner());
+
+    if n_errors > 0 {
+        Err(n_errors)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn write_new(_path: &Path, _proposal: &str) {
+    todo!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn write_new(path: &Path, proposal: &str) {
+    tokio::fs::write(path, proposal)
+        .await
+        .expect("couldn't write proposal");
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn renumber_source<S>(
+    _renumber: &Renumber<S>,
+    _source: &Path,
+    _number: u64,
+) -> Result<(), String>
+where
+    S: AsRef<str>,
+{
+    todo!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn renumber_source<S>(
+    renumber: &Renumber<S>,
+    source: &Path,
+    number: u64,
+) -> Result<(), String>
+where
+    S: AsRef<str>,
+{
+    use tokio::fs;
+
+    let original = fs::read_to_string(source)
+        .await
+        .expect("couldn't read source file");
+
+    let err = |e| format!("{}: {}", source.display(), e);
+
+    let old = renumber.number(&original).map_err(err)?;
+    let rewritten = renumber.rewrite(&original, number).map_err(err)?;
+
+    let directory = source.parent().unwrap_or_else(|| Path::new(""));
+    let destination = directory.join(renumber.file_name(number));
+    let old_assets = directory.join(renumber.assets_dir(old));
+    let new_assets = directory.join(renumber.assets_dir(number));
+
+    if old != number {
+        for taken in [&destination, &new_assets] {
+            if fs::try_exists(taken).await.unwrap_or(true) {
+                return Err(format!("{} already exists", taken.display()));
+            }
+        }
+    }
+
+    fs::write(&destination, rewritten)
+        .await
+        .expect("couldn't write source file");
+
+    if destination != source {
+        fs::remove_file(source)
+            .await
+            .expect("couldn't remove source file");
+    }
+
+    if old != number && fs::try_exists(&old_assets).await.unwrap_or(false) {
+        fs::rename(&old_assets, &new_assets)
+            .await
+            .expect("couldn't move asset directory");
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(target_arch = "wasm32", tokio::main(flavor = "current_thread"))]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::main)]
+async fn renumber(opts: RenumberOpts) -> Result<(), String> {
+    let options = match opts.config {
+        Some(ref path) => read_config(path).await,
+        None => Options::default(),
+    };
+
+    match options.lints {
+        Some(lints) => {
+            let renumber = Renumber::from_lints(lints.values());
+            renumber_source(&renumber, &opts.source, opts.number).await
+        }
+        None => {
+            let lints: Vec<_> = default_lints_enum().map(|(_, l)| l).collect();
+            let renumber = Renumber::from_lints(&lints);
+            renumber_source(&renumber, &opts.source, opts.number).await
+        }
     }
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn read_entries(_sources: Vec<PathBuf>) -> (Vec<(Entry, String)>, usize) {
+    todo!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn read_entries(sources: Vec<PathBuf>) -> (Vec<(Entry, String)>, usize) {
+    let mut entries = Vec::with_capacity(sources.len());
+    let mut failed = 0;
+
+    for source in sources {
+        let contents = tokio::fs::read_to_string(&source)
+            .await
+            .expect("couldn't read source file");
+
+        match Entry::parse(&contents) {
+            Ok(entry) => entries.push((entry, contents)),
+            Err(e) => {
+                eprintln!("{}: {}", source.display(), e);
+                failed += 1;
+            }
+        }
+    }
+
+    (entries, failed)
+}
+
+fn write_csv<W>(writer: W, entries: &[Entry]) -> Result<(), csv::Error>
+where
+    W: std::io::Write,
+{
+    let mut writer = csv::Writer::from_writer(writer);
+
+    writer.write_record([
+        "number", "title", "status", "type", "category", "authors", "created", "requires",
+    ])?;
+
+    for entry in entries {
+        let requires: Vec<_> = entry.requires.iter().map(u64::to_string).collect();
+
+        writer.write_record([
+            entry.number.to_string().as_str(),
+            &entry.title,
+            &entry.status,
+            &entry.type_,
+            entry.category.as_deref().unwrap_or_default(),
+            &entry.authors.join(", "),
+            &entry.created,
+            &requires.join(", "),
+        ])?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg_attr(target_arch = "wasm32", tokio::main(flavor = "current_thread"))]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::main)]
+async fn catalog(opts: CatalogOpts) -> Result<(), usize> {
+    let sources = collect_sources(opts.sources).await.unwrap();
+
+    let (entries, failed) = read_entries(sources).await;
+    let mut entries: Vec<_> = entries.into_iter().map(|(e, _)| e).collect();
+    entries.sort_by_key(|e| e.number);
 
     let stdout = std::io::stdout();
 
+    match opts.format {
+        CatalogFormat::Json => {
+            serde_json::to_writer_pretty(&stdout, &entries).unwrap();
+            println!();
+        }
+        CatalogFormat::Csv => write_csv(&stdout, &entries).unwrap(),
+    }
+
+    if failed > 0 {
+        Err(failed)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", tokio::main(flavor = "current_thread"))]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::main)]
+async fn graph(opts: GraphOpts) -> Result<(), usize> {
     let sources = collect_sources(opts.sources).await.unwrap();
 
-    let reporter = match opts.format {
-        Format::Json => EitherReporter::Json(Json::default()),
-        Format::Text => EitherReporter::Text(Text::default()),
-    };
+    let (entries, failed) = read_entries(sources).await;
 
-    let reporter = AdditionalHelp::new(reporter, |t: &str| {
-        Ok(format!("see https://ethereum.github.io/eipw/{}/", t))
-    });
-    let reporter = Count::new(reporter);
+    let mut graph = Graph::default();
+
+    for (entry, contents) in &entries {
+        graph.insert(entry);
+
+        if opts.references {
+            // `read_entries` already found the preamble, so this can't fail.
+            let (_, body) = Preamble::split(contents).unwrap();
+            graph.insert_references(entry.number, body).unwrap();
+        }
+    }
+
+    if let Some(number) = opts.deps_of {
+        graph = graph.deps_of(number);
+    } else if let Some(number) = opts.dependents_of {
+        graph = graph.dependents_of(number);
+    }
+
+    match opts.format {
+        GraphFormat::Dot => print!("{}", graph.to_dot()),
+        GraphFormat::Mermaid => print!("{}", graph.to_mermaid()),
+        GraphFormat::Json => {
+            serde_json::to_writer_pretty(std::io::stdout(), &graph).unwrap();
+            println!();
+        }
+    }
 
-    let options: Options;
-    let mut linter;
-    if let Some(ref path) = opts.config {
-        options = read_config(path).await;
-        let options_iter = options.to_iters();
-        linter = Linter::with_options(reporter, options_iter);
+    if failed > 0 {
+        Err(failed)
     } else {
-        linter = Linter::new(reporter);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Lints {
+    lints: HashMap<String, DefaultLint<String>>,
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn read_baseline(_path: &Path) -> Vec<BaselineEntry> {
+    todo!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn read_baseline(path: &Path) -> Vec<BaselineEntry> {
+    let contents = tokio::fs::read_to_string(path)
+        .await
+        .expect("couldn't read baseline file");
+
+    serde_json::from_str(&contents).expect("couldn't parse baseline file")
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn write_baseline(_path: &Path, _entries: &[BaselineEntry]) {
+    todo!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn write_baseline(path: &Path, entries: &[BaselineEntry]) {
+    let mut contents = serde_json::to_string_pretty(entries).unwrap();
+    contents.push('\n');
+
+    tokio::fs::write(path, contents)
+        .await
+        .expect("couldn't write baseline file");
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn apply_fixes(_fixes: Vec<Fix>) {
+    todo!()
+}
+
+/// Applies `fixes` to the files they were suggested for.
+#[cfg(not(target_arch = "wasm32"))]
+async fn apply_fixes(fixes: Vec<Fix>) {
+    use std::collections::BTreeMap;
+    use tokio::fs;
+
+    let mut by_origin: BTreeMap<_, Vec<_>> = BTreeMap::new();
+    for fix in fixes {
+        if let Some(origin) = fix.origin.clone() {
+            by_origin.entry(origin).or_default().push(fix);
+        }
+    }
+
+    for (origin, fixes) in by_origin {
+        let original = fs::read_to_string(&origin)
+            .await
+            .expect("couldn't read source file");
+
+        let (fixed, skipped) = eipw_lint::fix::apply(&original, &fixes);
+
+        fs::write(&origin, fixed)
+            .await
+            .expect("couldn't write source file");
+
+        eprintln!("{}: applied {} fixes", origin, fixes.len() - skipped.len());
+
+        if !skipped.is_empty() {
+            eprintln!(
+                "{}: skipped {} overlapping fixes, run again to apply them",
+                origin,
+                skipped.len()
+            );
+        }
     }
+}
 
+/// Applies the lint selection flags (eg. `--deny`) from `opts`.
+fn configure<'a, R>(mut linter: Linter<'a, R>, opts: &Opts) -> Linter<'a, R> {
     if opts.no_default_lints {
         linter = linter.clear_lints();
     }
 
-    for allow in opts.allow {
-        linter = linter.allow(&allow);
+    for allow in &opts.allow {
+        linter = linter.allow(allow);
     }
 
     if !opts.warn.is_empty() {
         let mut lints: HashMap<_, _> = default_lints().collect();
-        for warn in opts.warn {
+        for warn in &opts.warn {
             let (k, v) = lints.remove_entry(warn.as_str()).unwrap();
             linter = linter.warn(k, v);
         }
@@ -227,23 +854,291 @@ async fn run(opts: Opts) -> Result<(), usize> {
 
     if !opts.deny.is_empty() {
         let mut lints: HashMap<_, _> = default_lints().collect();
-        for deny in opts.deny {
+        for deny in &opts.deny {
             let (k, v) = lints.remove_entry(deny.as_str()).unwrap();
             linter = linter.deny(k, v);
         }
     }
 
+    linter
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn watch(_opts: &Opts, _options: Option<&Options>) {
+    todo!()
+}
+
+/// Results of checking one source, and the paths it depends on.
+#[cfg(not(target_arch = "wasm32"))]
+struct Checked {
+    report: String,
+    errors: usize,
+    depends: std::collections::BTreeSet<PathBuf>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn canonical(path: &Path) -> PathBuf {
+    if let Ok(canonical) = std::fs::canonicalize(path) {
+        return canonical;
+    }
+
+    // Paths that don't exist yet (eg. a missing `requires` target) can't be
+    // canonicalized, but the directory they'll be created in usually can.
+    let parent = match path.parent() {
+        Some(p) if p.as_os_str().is_empty() => Path::new("."),
+        Some(p) => p,
+        None => return path.to_path_buf(),
+    };
+
+    match path.file_name() {
+        Some(name) => canonical(parent).join(name),
+        None => path.to_path_buf(),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn check_one(opts: &Opts, options: Option<&Options>, source: &Path) -> Checked {
+    use eipw_lint::fetch::{DefaultFetch, Recording};
+
+    let recording = Recording::new(DefaultFetch);
+
+    let reporter = AdditionalHelp::new(Text::<String>::default(), |t: &str| {
+        Ok(format!("see https://ethereum.github.io/eipw/{}/", t))
+    });
+    let reporter = Count::new(reporter);
+
+    let linter = match options {
+        Some(options) => Linter::with_options(reporter, options.to_iters()),
+        None => Linter::new(reporter),
+    };
+
+    let result = configure(linter, opts)
+        .set_fetch(recording.clone())
+        .check_file(source)
+        .run()
+        .await;
+
+    let (report, errors) = match result {
+        Ok(reporter) => {
+            let errors = reporter.counts().error;
+            (reporter.into_inner().into_inner().into_inner(), errors)
+        }
+        Err(e) => (format!("{}: {}\n", source.display(), e), 1),
+    };
+
+    let mut depends: std::collections::BTreeSet<_> =
+        recording.paths().iter().map(|p| canonical(p)).collect();
+    depends.insert(canonical(source));
+
+    Checked {
+        report,
+        errors,
+        depends,
+    }
+}
+
+/// Checks the sources in `opts`, then re-checks them whenever they (or the
+/// files they fetch) change. Never returns.
+#[cfg(not(target_arch = "wasm32"))]
+async fn watch(opts: &Opts, options: Option<&Options>) {
+    use notify::event::EventKind;
+    use notify::{RecursiveMode, Watcher};
+
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).expect("couldn't watch for changes");
+    let mut watched = BTreeSet::new();
+
+    let mut checked: BTreeMap<PathBuf, Checked> = BTreeMap::new();
+
+    // `None` means everything needs to be checked.
+    let mut changed: Option<BTreeSet<PathBuf>> = None;
+
+    loop {
+        let sources = match collect_sources(opts.sources.clone()).await {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("unable to read sources: {}", e);
+                Vec::new()
+            }
+        };
+
+        let before = checked.len();
+        checked.retain(|path, _| sources.contains(path));
+        let mut redraw = changed.is_none() || before != checked.len();
+
+        for source in &sources {
+            let stale = match (&changed, checked.get(source)) {
+                (Some(changed), Some(c)) => changed
+                    .iter()
+                    .any(|p| c.depends.iter().any(|d| p.starts_with(d))),
+                _ => true,
+            };
+
+            if stale {
+                checked.insert(source.clone(), check_one(opts, options, source).await);
+                redraw = true;
+            }
+        }
+
+        // Watch directories rather than files, since editors often replace
+        // files instead of writing to them.
+        let wanted = opts
+            .sources
+            .iter()
+            .map(|s| canonical(s))
+            .chain(checked.values().flat_map(|c| c.depends.iter().cloned()))
+            .filter_map(|p| {
+                if p.is_dir() {
+                    // Listed directories (eg. assets) include subdirectories.
+                    Some((p, RecursiveMode::Recursive))
+                } else {
+                    Some((p.parent()?.to_path_buf(), RecursiveMode::NonRecursive))
+                }
+            });
+
+        for (path, mode) in wanted {
+            if watched.contains(&path) {
+                continue;
+            }
+
+            // Missing directories can't be watched, but might appear later.
+            if watcher.watch(&path, mode).is_ok() {
+                watched.insert(path);
+            }
+        }
+
+        if redraw {
+            // Clear the terminal, and move the cursor to the top left.
+            print!("\x1B[2J\x1B[1;1H");
+
+            let mut n_errors = 0;
+            for c in checked.values() {
+                print!("{}", c.report);
+                n_errors += c.errors;
+            }
+
+            if n_errors > 0 {
+                println!("validation failed with {} errors :(", n_errors);
+            }
+
+            println!("watching for changes...");
+        }
+
+        let mut paths = BTreeSet::new();
+        let mut event = rx.recv().expect("stopped watching for changes");
+
+        loop {
+            if let Ok(event) = event {
+                if !matches!(event.kind, EventKind::Access(_)) {
+                    paths.extend(event.paths.iter().map(|p| canonical(p)));
+                }
+            }
+
+            // Wait for a burst of changes (eg. a save) to settle.
+            event = match rx.recv_timeout(Duration::from_millis(100)) {
+                Ok(e) => e,
+                Err(_) => break,
+            };
+        }
+
+        changed = Some(paths);
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", tokio::main(flavor = "current_thread"))]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::main)]
+async fn run(opts: Opts) -> Result<(), usize> {
+    if opts.list_lints {
+        list_lints();
+        return Ok(());
+    }
+
+    if opts.defaults {
+        defaults();
+        return Ok(());
+    }
+
+    let options = match opts.config {
+        Some(ref path) => Some(read_config(path).await),
+        None => None,
+    };
+
+    if opts.watch {
+        watch(&opts, options.as_ref()).await;
+        return Ok(());
+    }
+
+    let stdout = std::io::stdout();
+
+    let sources = collect_sources(opts.sources.clone()).await.unwrap();
+
+    let reporter = match opts.format {
+        Format::Json => EitherReporter::Json(Json::default()),
+        Format::Text => EitherReporter::Text(Text::default()),
+        Format::Github => EitherReporter::Github(Github::default()),
+        Format::Junit => EitherReporter::Junit(Junit::default()),
+        Format::Checkstyle => EitherReporter::Checkstyle(Checkstyle::default()),
+    };
+
+    let reporter = AdditionalHelp::new(reporter, |t: &str| {
+        Ok(format!("see https://ethereum.github.io/eipw/{}/", t))
+    });
+    let reporter = Count::new(reporter);
+
+    let known = match opts.baseline {
+        Some(ref path) => read_baseline(path).await,
+        None => Vec::new(),
+    };
+    let reporter = Baseline::new(reporter, known);
+    let reporter = Fixes::new(reporter);
+
+    let mut linter = match options {
+        Some(ref options) => Linter::with_options(reporter, options.to_iters()),
+        None => Linter::new(reporter),
+    };
+
+    linter = configure(linter, &opts);
+
     for source in &sources {
         linter = linter.check_file(source);
     }
 
-    let reporter = linter.run().await.unwrap();
+    let (reporter, fixes) = linter.run().await.unwrap().into_parts();
+
+    if opts.fix {
+        apply_fixes(fixes).await;
+    }
+
+    if let Some(ref path) = opts.write_baseline {
+        write_baseline(path, &reporter.seen()).await;
+    }
+
+    let stale = reporter.stale(sources.iter().map(|s| s.to_string_lossy()));
+    if !stale.is_empty() {
+        eprintln!("{} baseline entries no longer occur:", stale.len());
+        for entry in stale {
+            eprintln!(
+                "\t{} {} {}",
+                entry.origin.as_deref().unwrap_or("<unknown>"),
+                entry.slug.as_deref().unwrap_or("<unknown>"),
+                entry.fingerprint,
+            );
+        }
+    }
 
+    let reporter = reporter.into_inner();
     let n_errors = reporter.counts().error;
 
     match reporter.into_inner().into_inner() {
         EitherReporter::Json(j) => serde_json::to_writer_pretty(&stdout, &j).unwrap(),
         EitherReporter::Text(t) => print!("{}", t.into_inner()),
+        EitherReporter::Github(g) => print!("{}", g.into_inner()),
+        EitherReporter::Junit(j) => print!("{}", j),
+        EitherReporter::Checkstyle(c) => print!("{}", c),
     }
 
     if n_errors > 0 {
@@ -256,6 +1151,50 @@ async fn run(opts: Opts) -> Result<(), usize> {
 fn main() {
     let opts = Opts::parse();
 
+    match opts.command {
+        Some(Command::Fmt(fmt_opts)) => {
+            if let Err(failed) = fmt(fmt_opts) {
+                if failed.unparsed > 0 {
+                    eprintln!("{} files could not be parsed", failed.unparsed);
+                }
+                if failed.unformatted > 0 {
+                    eprintln!("{} files are not formatted", failed.unformatted);
+                }
+                std::process::exit(1);
+            }
+            return;
+        }
+        Some(Command::New(new_opts)) => {
+            if let Err(n_errors) = new(*new_opts) {
+                eprintln!("generated proposal has {} errors", n_errors);
+                std::process::exit(1);
+            }
+            return;
+        }
+        Some(Command::Catalog(catalog_opts)) => {
+            if let Err(n_files) = catalog(catalog_opts) {
+                eprintln!("{} proposals could not be read", n_files);
+                std::process::exit(1);
+            }
+            return;
+        }
+        Some(Command::Graph(graph_opts)) => {
+            if let Err(n_files) = graph(graph_opts) {
+                eprintln!("{} proposals could not be read", n_files);
+                std::process::exit(1);
+            }
+            return;
+        }
+        Some(Command::Renumber(renumber_opts)) => {
+            if let Err(e) = renumber(renumber_opts) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+            return;
+        }
+        None => (),
+    }
+
     if let Err(n_errors) = run(opts) {
         eprintln!("validation failed with {} errors :(", n_errors);
         std::process::exit(1);